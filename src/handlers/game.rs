@@ -1,5 +1,5 @@
 use crate::{
-    models::{ArtPiece, GameState},
+    models::{Game, GameState},
     state::AppState,
 };
 use askama::Template;
@@ -18,14 +18,20 @@ pub struct GameTemplate {
     player_name: String,
     player_chips: i32,
     round: usize,
-    current_art: ArtPiece,
+    current_art_html: String,
     current_highest_bid: u32,
     current_highest_bidder: String,
     has_bid: bool,
+    is_sealed: bool,
+    round_reveal_html: String,
+    price_ticker_html: String,
     timer_seconds: i64,
     collection_count: usize,
     collection_score: u32,
     is_host: bool,
+    trading_enabled: bool,
+    market_board_html: String,
+    preview_enabled: bool,
 }
 
 #[derive(Deserialize)]
@@ -70,12 +76,18 @@ pub async fn game_view(
         None => return Redirect::to("/").into_response(),
     };
 
-    let current_art = match &game.current_art {
-        Some(art) => art.clone(),
-        None => return Redirect::to("/").into_response(),
-    };
+    // Player auctioneer rounds have no painting until the seller picks one
+    if game.current_art.is_empty() && game.seller_id.is_none() {
+        return Redirect::to("/").into_response();
+    }
 
-    let highest_bid = game.get_highest_bid();
+    // Sealed bids stay hidden until the round is revealed
+    let is_sealed = game.config.auction_type.is_sealed();
+    let highest_bid = if is_sealed {
+        None
+    } else {
+        game.get_highest_bid()
+    };
     let has_bid = highest_bid.is_some();
     let current_highest_bid = highest_bid.as_ref().map(|b| b.amount).unwrap_or(0);
     let current_highest_bidder = highest_bid
@@ -92,14 +104,20 @@ pub async fn game_view(
         player_name: player.name.clone(),
         player_chips: player.chips,
         round,
-        current_art,
+        current_art_html: crate::handlers::partials::render_current_art_partial(game),
         current_highest_bid,
         current_highest_bidder,
         has_bid,
+        is_sealed,
+        round_reveal_html: crate::handlers::partials::render_round_reveal_partial(game),
+        price_ticker_html: crate::handlers::partials::render_price_ticker_partial(game),
         timer_seconds,
         collection_count: player.collection.len(),
-        collection_score: player.calculate_score(),
+        collection_score: game.final_score(player),
         is_host,
+        trading_enabled: game.config.trading_enabled,
+        preview_enabled: game.config.preview_count > 0,
+        market_board_html: crate::handlers::partials::render_market_board_partial(game),
     };
 
     match template.render() {
@@ -150,6 +168,415 @@ pub async fn place_bid(
                     String::from("<!-- trigger -->"),
                 )
                 .await;
+            // In once-around rounds the last player's bid ends the round
+            crate::services::timer::resolve_game_round(&state, &game_id).await;
+
+            Ok("OK")
+        }
+        Err(e) => Err(Html(e)),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ProxyBidForm {
+    player_id: String,
+    max_amount: u32,
+}
+
+pub async fn place_proxy_bid(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<ProxyBidForm>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    match crate::services::game_engine::place_proxy_bid(game, &form.player_id, form.max_amount) {
+        Ok(_) => {
+            // The maximum itself stays hidden; only bids it triggered are shown
+            let bid_html = crate::handlers::partials::render_bid_partial(game);
+
+            drop(games);
+
+            state.broadcast_sse(&game_id, "bid-placed", bid_html).await;
+            state
+                .broadcast_sse(&game_id, "player-updated", String::from("<!-- update -->"))
+                .await;
+            state
+                .broadcast_sse(&game_id, "timer-update", String::from("<!-- trigger -->"))
+                .await;
+            state
+                .broadcast_sse(
+                    &game_id,
+                    "bidding-updated",
+                    String::from("<!-- trigger -->"),
+                )
+                .await;
+            // A proxy can close the round early if everyone else has passed
+            crate::services::timer::resolve_game_round(&state, &game_id).await;
+
+            Ok("OK")
+        }
+        Err(e) => Err(Html(e)),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct BuyForm {
+    player_id: String,
+}
+
+pub async fn buy_art(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<BuyForm>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    match crate::services::game_engine::buy_current_art(game, &form.player_id) {
+        Ok(_) => {
+            drop(games);
+
+            // First buyer takes it - resolve right away instead of waiting for the next tick
+            crate::services::timer::resolve_game_round(&state, &game_id).await;
+
+            Ok("OK")
+        }
+        Err(e) => Err(Html(e)),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct OfferForm {
+    player_id: String,
+    art_id: usize,
+}
+
+pub async fn offer_art(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<OfferForm>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    match crate::services::game_engine::offer_art(game, &form.player_id, form.art_id) {
+        Ok(_) => {
+            let art_html = crate::handlers::partials::render_current_art_partial(game);
+
+            drop(games);
+
+            // Show the chosen painting and let the host start the bidding
+            state
+                .broadcast_sse(&game_id, "round-resolved", art_html)
+                .await;
+            state
+                .broadcast_sse(&game_id, "timer-update", String::from("<!-- trigger -->"))
+                .await;
+            state
+                .broadcast_sse(
+                    &game_id,
+                    "bidding-updated",
+                    String::from("<!-- trigger -->"),
+                )
+                .await;
+            state
+                .broadcast_sse(&game_id, "player-updated", String::from("<!-- update -->"))
+                .await;
+
+            Ok("OK")
+        }
+        Err(e) => Err(Html(e)),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SellForm {
+    player_id: String,
+    art_id: usize,
+}
+
+pub async fn sell_art(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<SellForm>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    let deck_size = game.deck.len();
+    match crate::services::game_engine::sell_to_bank(game, &form.player_id, form.art_id) {
+        Ok(_) => {
+            let deck_changed = game.deck.len() != deck_size;
+
+            drop(games);
+
+            // A painting sold back into the deck changes what's coming up
+            if deck_changed {
+                state
+                    .broadcast_sse(&game_id, "deck-updated", String::from("<!-- trigger -->"))
+                    .await;
+            }
+
+            // Collection, chips and what the player can afford all changed
+            state
+                .broadcast_sse(&game_id, "player-updated", String::from("<!-- update -->"))
+                .await;
+            state
+                .broadcast_sse(
+                    &game_id,
+                    "bidding-updated",
+                    String::from("<!-- trigger -->"),
+                )
+                .await;
+            state
+                .broadcast_sse(&game_id, "trades-updated", String::from("<!-- trigger -->"))
+                .await;
+
+            Ok("OK")
+        }
+        Err(e) => Err(Html(e)),
+    }
+}
+
+pub async fn authenticate_art(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<SellForm>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    match crate::services::game_engine::authenticate_art(game, &form.player_id, form.art_id) {
+        Ok(_) => {
+            drop(games);
+
+            // Only the player's own collection shows the verdict; everyone sees their chips drop
+            state
+                .broadcast_sse(&game_id, "player-updated", String::from("<!-- update -->"))
+                .await;
+            state
+                .broadcast_sse(
+                    &game_id,
+                    "bidding-updated",
+                    String::from("<!-- trigger -->"),
+                )
+                .await;
+
+            Ok("OK")
+        }
+        Err(e) => Err(Html(e)),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct LoanForm {
+    player_id: String,
+    amount: u32,
+}
+
+pub async fn take_loan(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<LoanForm>,
+) -> Result<&'static str, Html<String>> {
+    update_chips(&state, &game_id, |game| {
+        crate::services::game_engine::take_loan(game, &form.player_id, form.amount)
+    })
+    .await
+}
+
+pub async fn repay_loan(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<LoanForm>,
+) -> Result<&'static str, Html<String>> {
+    update_chips(&state, &game_id, |game| {
+        crate::services::game_engine::repay_loan(game, &form.player_id, form.amount)
+    })
+    .await
+}
+
+#[derive(Deserialize)]
+pub struct TransferForm {
+    player_id: String,
+    teammate_id: String,
+    amount: u32,
+}
+
+pub async fn transfer_chips(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<TransferForm>,
+) -> Result<&'static str, Html<String>> {
+    update_chips(&state, &game_id, |game| {
+        crate::services::game_engine::transfer_chips(
+            game,
+            &form.player_id,
+            &form.teammate_id,
+            form.amount,
+        )
+    })
+    .await
+}
+
+/// Apply a chip change and refresh everyone's chips and bidding limits
+async fn update_chips(
+    state: &AppState,
+    game_id: &str,
+    apply: impl FnOnce(&mut crate::models::Game) -> Result<(), String>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    match apply(game) {
+        Ok(_) => {
+            drop(games);
+
+            state
+                .broadcast_sse(game_id, "player-updated", String::from("<!-- update -->"))
+                .await;
+            state
+                .broadcast_sse(game_id, "bidding-updated", String::from("<!-- trigger -->"))
+                .await;
+
+            Ok("OK")
+        }
+        Err(e) => Err(Html(e)),
+    }
+}
+
+pub async fn propose_trade(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<&'static str, Html<String>> {
+    // Painting checkboxes repeat their field name, so read the raw pairs
+    let field = |name: &str| {
+        fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+    // Checkboxes carry the game's opaque art handles, not art ids
+    let art_ids = |game: &Game, name: &str| -> Vec<usize> {
+        fields
+            .iter()
+            .filter(|(key, _)| key == name)
+            .filter_map(|(_, value)| value.parse().ok())
+            .filter_map(|handle| game.art_id_for_handle(handle))
+            .collect()
+    };
+    let chips = |name: &str| field(name).and_then(|v| v.parse().ok()).unwrap_or(0);
+
+    let (Some(player_id), Some(to_id)) = (field("player_id"), field("to_id")) else {
+        return Err(Html("Missing trading partner".to_string()));
+    };
+
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    let offered_art = art_ids(game, "offered_art");
+    let requested_art = art_ids(game, "requested_art");
+    match crate::services::trading::propose_trade(
+        game,
+        player_id,
+        to_id,
+        offered_art,
+        requested_art,
+        chips("offered_chips"),
+        chips("requested_chips"),
+    ) {
+        Ok(_) => {
+            drop(games);
+
+            state
+                .broadcast_sse(&game_id, "trades-updated", String::from("<!-- trigger -->"))
+                .await;
+
+            Ok("OK")
+        }
+        Err(e) => Err(Html(e)),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TradeForm {
+    player_id: String,
+}
+
+pub async fn accept_trade(
+    Path((game_id, trade_id)): Path<(String, usize)>,
+    State(state): State<AppState>,
+    Form(form): Form<TradeForm>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    let completed_html = crate::services::trading::accept_trade(game, &form.player_id, trade_id)
+        .map(|trade| crate::handlers::partials::render_trade_completed_partial(game, &trade));
+
+    drop(games);
+
+    // A trade that fell through is withdrawn, so refresh the offers either way
+    state
+        .broadcast_sse(&game_id, "trades-updated", String::from("<!-- trigger -->"))
+        .await;
+
+    let completed_html = completed_html.map_err(Html)?;
+    state
+        .broadcast_sse(&game_id, "trade-completed", completed_html)
+        .await;
+    state
+        .broadcast_sse(&game_id, "player-updated", String::from("<!-- update -->"))
+        .await;
+
+    Ok("OK")
+}
+
+pub async fn reject_trade(
+    Path((game_id, trade_id)): Path<(String, usize)>,
+    State(state): State<AppState>,
+    Form(form): Form<TradeForm>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    match crate::services::trading::reject_trade(game, &form.player_id, trade_id) {
+        Ok(_) => {
+            drop(games);
+
+            state
+                .broadcast_sse(&game_id, "trades-updated", String::from("<!-- trigger -->"))
+                .await;
 
             Ok("OK")
         }
@@ -159,17 +586,32 @@ pub async fn place_bid(
 
 #[derive(Deserialize)]
 pub struct PassForm {
-    #[allow(dead_code)]
     player_id: String,
 }
 
 pub async fn pass_bid(
-    Path(_game_id): Path<String>,
-    State(_state): State<AppState>,
-    Form(_form): Form<PassForm>,
-) -> &'static str {
-    // Pass just means not bidding - no action needed
-    "OK"
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<PassForm>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    match crate::services::game_engine::pass_bid(game, &form.player_id) {
+        Ok(_) => {
+            drop(games);
+
+            crate::services::timer::broadcast_turn_update(&state, &game_id).await;
+            // The pass may have been the last action of the round
+            crate::services::timer::resolve_game_round(&state, &game_id).await;
+
+            Ok("OK")
+        }
+        Err(e) => Err(Html(e)),
+    }
 }
 
 pub async fn player_info(
@@ -219,7 +661,38 @@ pub async fn collection_display(
         None => return Html("Player not found".to_string()),
     };
 
-    let html = crate::handlers::partials::render_collection_display_partial(player);
+    let html = crate::handlers::partials::render_collection_display_partial(game, player);
+
+    Html(html)
+}
+
+pub async fn upcoming_lots(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+) -> Html<String> {
+    let games = state.games.read().await;
+    let game = match games.get(&game_id) {
+        Some(g) => g,
+        None => return Html("Game not found".to_string()),
+    };
+
+    Html(crate::handlers::partials::render_upcoming_lots_partial(
+        game,
+    ))
+}
+
+pub async fn trade_panel(
+    Path(game_id): Path<String>,
+    axum::extract::Query(GameQuery { player_id }): axum::extract::Query<GameQuery>,
+    State(state): State<AppState>,
+) -> Html<String> {
+    let games = state.games.read().await;
+    let game = match games.get(&game_id) {
+        Some(g) => g,
+        None => return Html("Game not found".to_string()),
+    };
+
+    let html = crate::handlers::partials::render_trade_panel_partial(game, &player_id, &game_id);
 
     Html(html)
 }
@@ -279,8 +752,18 @@ pub async fn start_round(
 
     match crate::services::game_engine::start_round(game) {
         Ok(_) => {
+            let ticker_html = crate::handlers::partials::render_price_ticker_partial(game);
+
             drop(games);
 
+            state
+                .broadcast_sse(&game_id, "price-ticker", ticker_html)
+                .await;
+            // Starting the round closes the trading window
+            state
+                .broadcast_sse(&game_id, "trades-updated", String::from("<!-- trigger -->"))
+                .await;
+
             // Broadcast timer update and bidding area update to all players
             state
                 .broadcast_sse(&game_id, "timer-update", String::from("<!-- trigger -->"))
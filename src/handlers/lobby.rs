@@ -1,4 +1,12 @@
-use crate::{models::Player, state::AppState};
+use crate::{
+    models::{
+        Artist, AuctionType, BlindHides, BlindReveal, DeckStrategy, FloorRule, GameMode, GameState,
+        IncrementRule, Movement, Player, ScoringMode, SellBackBasis, SellBackDestination,
+        TeamScoring, TieBreaker,
+    },
+    services::art_database::{all_artists, all_movements},
+    state::AppState,
+};
 use askama_axum::Template;
 use axum::{
     Form,
@@ -110,15 +118,14 @@ pub struct LobbyTemplate {
     player_id: String,
     is_host: bool,
     players: Vec<PlayerInfo>,
-    starting_chips: u32,
-    bid_timer_seconds: u64,
-    num_rounds: usize,
+    settings_html: String,
 }
 
 #[derive(Clone)]
 pub struct PlayerInfo {
     pub name: String,
     pub is_host: bool,
+    pub team: Option<u8>, // Only shown when playing in teams
 }
 
 #[derive(Deserialize)]
@@ -144,25 +151,78 @@ pub async fn lobby_view(
         .map(|p| PlayerInfo {
             name: p.name.clone(),
             is_host: game.is_host(&p.id),
+            team: p.team.filter(|_| game.config.teams_enabled),
         })
         .collect();
 
+    // Settings markup is shared with the SSE updates so both always match
+    let settings_html = if is_host {
+        crate::handlers::partials::render_lobby_settings_host(game, &game_id, &player_id)
+    } else {
+        crate::handlers::partials::render_lobby_settings_player(game)
+    };
+
     Ok(LobbyTemplate {
         game_id,
         player_id,
         is_host,
         players,
-        starting_chips: game.config.starting_chips,
-        bid_timer_seconds: game.config.bid_timer_seconds,
-        num_rounds: game.config.num_rounds,
+        settings_html,
     })
 }
 
 #[derive(Deserialize)]
 pub struct ConfigForm {
+    game_mode: Option<GameMode>,
     starting_chips: u32,
     bid_timer_seconds: u64,
     num_rounds: usize,
+    lot_size: Option<usize>,
+    auction_type: Option<AuctionType>,
+    scoring: Option<ScoringMode>,
+    tie_breaker: Option<TieBreaker>,
+    chips_per_point: Option<u32>,
+    teams_enabled: Option<bool>,
+    team_scoring: Option<TeamScoring>,
+    deck_strategy: Option<DeckStrategy>,
+    pieces_per_focus: Option<usize>,
+    one_star_weight: Option<u32>,
+    two_star_weight: Option<u32>,
+    three_star_weight: Option<u32>,
+    seed: Option<String>, // Blank for a random shuffle
+    preview_count: Option<usize>,
+    blind_lot_percent: Option<u32>,
+    blind_hides: Option<BlindHides>,
+    blind_reveal: Option<BlindReveal>,
+    forgery_percent: Option<u32>,
+    forgery_penalty: Option<u32>,
+    authentication_enabled: Option<bool>,
+    authentication_cost: Option<u32>,
+    second_price_minimum: Option<u32>,
+    dutch_start_price: Option<u32>,
+    dutch_price_step: Option<u32>,
+    dutch_floor_price: Option<u32>,
+    dutch_floor_rule: Option<FloorRule>,
+    turn_timer_seconds: Option<u64>,
+    soft_close_window_seconds: Option<u64>,
+    soft_close_extension_seconds: Option<u64>,
+    max_round_seconds: Option<u64>,
+    min_increment: Option<u32>,
+    increment_rule: Option<IncrementRule>,
+    reserve_per_star: Option<u32>,
+    round_stipend: Option<u32>,
+    interest_percent: Option<u32>,
+    trading_enabled: Option<bool>,
+    sell_back_enabled: Option<bool>,
+    sell_back_basis: Option<SellBackBasis>,
+    sell_back_percent: Option<u32>,
+    sell_back_destination: Option<SellBackDestination>,
+    market_enabled: Option<bool>,
+    season_length: Option<usize>,
+    secret_objectives: Option<bool>,
+    loans_enabled: Option<bool>,
+    loan_limit: Option<u32>,
+    loan_interest_percent: Option<u32>,
 }
 
 pub async fn configure(
@@ -172,22 +232,172 @@ pub async fn configure(
 ) -> &'static str {
     let mut games = state.games.write().await;
     if let Some(game) = games.get_mut(&game_id) {
+        if let Some(game_mode) = form.game_mode {
+            game.config.game_mode = game_mode;
+        }
         game.config.starting_chips = form.starting_chips.clamp(10, 1000);
         game.config.bid_timer_seconds = form.bid_timer_seconds.clamp(10, 120);
-        game.config.num_rounds = form.num_rounds.clamp(1, 90);
+        if let Some(lot_size) = form.lot_size {
+            game.config.lot_size = lot_size.clamp(1, 5);
+        }
+        // The deck only holds 90 paintings, so bigger lots mean fewer rounds
+        game.config.num_rounds = form.num_rounds.clamp(1, 90 / game.config.lot_size);
+        if let Some(auction_type) = form.auction_type {
+            game.config.auction_type = auction_type;
+        }
+        if let Some(scoring) = form.scoring {
+            game.config.scoring = scoring;
+        }
+        if let Some(tie_breaker) = form.tie_breaker {
+            game.config.tie_breaker = tie_breaker;
+        }
+        if let Some(chips_per_point) = form.chips_per_point {
+            game.config.chips_per_point = chips_per_point.min(100);
+        }
+        if let Some(teams_enabled) = form.teams_enabled {
+            game.config.teams_enabled = teams_enabled;
+        }
+        if let Some(team_scoring) = form.team_scoring {
+            game.config.team_scoring = team_scoring;
+        }
+        if let Some(deck_strategy) = form.deck_strategy {
+            game.config.deck_strategy = deck_strategy;
+        }
+        if let Some(pieces) = form.pieces_per_focus {
+            game.config.pieces_per_focus = pieces.clamp(1, 9);
+        }
+        for (i, weight) in [
+            form.one_star_weight,
+            form.two_star_weight,
+            form.three_star_weight,
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            if let Some(weight) = weight {
+                game.config.star_weights[i] = weight.min(10);
+            }
+        }
+        if let Some(minimum) = form.second_price_minimum {
+            game.config.second_price_minimum = minimum.clamp(1, 100);
+        }
+        if let Some(start_price) = form.dutch_start_price {
+            game.config.dutch_start_price = start_price.clamp(1, 500);
+        }
+        if let Some(step) = form.dutch_price_step {
+            game.config.dutch_price_step = step.clamp(1, 50);
+        }
+        if let Some(floor_price) = form.dutch_floor_price {
+            // A price of 0 can't be paid, so the clock stops at 1
+            game.config.dutch_floor_price = floor_price.clamp(1, game.config.dutch_start_price);
+        }
+        if let Some(floor_rule) = form.dutch_floor_rule {
+            game.config.dutch_floor_rule = floor_rule;
+        }
+        if let Some(turn_seconds) = form.turn_timer_seconds {
+            game.config.turn_timer_seconds = turn_seconds.clamp(5, 60);
+        }
+        if let Some(window) = form.soft_close_window_seconds {
+            game.config.soft_close_window_seconds = window.clamp(0, 60);
+        }
+        if let Some(extension) = form.soft_close_extension_seconds {
+            game.config.soft_close_extension_seconds = extension.clamp(1, 60);
+        }
+        if let Some(max_seconds) = form.max_round_seconds {
+            game.config.max_round_seconds = max_seconds.clamp(15, 600);
+        }
+        if let Some(increment) = form.min_increment {
+            game.config.min_increment = increment.clamp(1, 100);
+        }
+        if let Some(increment_rule) = form.increment_rule {
+            game.config.increment_rule = increment_rule;
+        }
+        if let Some(reserve) = form.reserve_per_star {
+            game.config.reserve_per_star = reserve.min(50);
+        }
+        if let Some(stipend) = form.round_stipend {
+            game.config.round_stipend = stipend.min(100);
+        }
+        if let Some(interest) = form.interest_percent {
+            game.config.interest_percent = interest.min(50);
+        }
+        if let Some(preview_count) = form.preview_count {
+            game.config.preview_count = preview_count.min(10);
+        }
+        if let Some(blind_lot_percent) = form.blind_lot_percent {
+            game.config.blind_lot_percent = blind_lot_percent.min(100);
+        }
+        if let Some(blind_hides) = form.blind_hides {
+            game.config.blind_hides = blind_hides;
+        }
+        if let Some(blind_reveal) = form.blind_reveal {
+            game.config.blind_reveal = blind_reveal;
+        }
+        if let Some(forgery_percent) = form.forgery_percent {
+            game.config.forgery_percent = forgery_percent.min(50);
+        }
+        if let Some(forgery_penalty) = form.forgery_penalty {
+            game.config.forgery_penalty = forgery_penalty.min(20);
+        }
+        if let Some(authentication_enabled) = form.authentication_enabled {
+            game.config.authentication_enabled = authentication_enabled;
+        }
+        if let Some(authentication_cost) = form.authentication_cost {
+            game.config.authentication_cost = authentication_cost.clamp(1, 100);
+        }
+        if let Some(seed) = form.seed {
+            game.config.seed = seed.trim().parse().ok();
+        }
+        if let Some(trading_enabled) = form.trading_enabled {
+            game.config.trading_enabled = trading_enabled;
+        }
+        if let Some(sell_back_enabled) = form.sell_back_enabled {
+            game.config.sell_back_enabled = sell_back_enabled;
+        }
+        if let Some(basis) = form.sell_back_basis {
+            game.config.sell_back_basis = basis;
+        }
+        if let Some(percent) = form.sell_back_percent {
+            game.config.sell_back_percent = percent.clamp(10, 100);
+        }
+        if let Some(destination) = form.sell_back_destination {
+            game.config.sell_back_destination = destination;
+        }
+        if let Some(market_enabled) = form.market_enabled {
+            game.config.market_enabled = market_enabled;
+        }
+        if let Some(season_length) = form.season_length {
+            game.config.season_length = season_length.clamp(1, 30);
+        }
+        if let Some(secret_objectives) = form.secret_objectives {
+            game.config.secret_objectives = secret_objectives;
+        }
+        if let Some(loans_enabled) = form.loans_enabled {
+            game.config.loans_enabled = loans_enabled;
+        }
+        if let Some(loan_limit) = form.loan_limit {
+            game.config.loan_limit = loan_limit.clamp(10, 500);
+        }
+        if let Some(loan_interest) = form.loan_interest_percent {
+            game.config.loan_interest_percent = loan_interest.min(50);
+        }
 
         // Update all players' chips
         for player in game.players.values_mut() {
             player.chips = form.starting_chips as i32;
         }
 
-        // Broadcast config and player updates
+        // Broadcast config and player updates (team badges come and go with team play)
+        let players_html = crate::handlers::partials::render_lobby_players_card(game);
         let host_settings_html =
             crate::handlers::partials::render_lobby_settings_host(game, &game_id, &game.host_id);
         let player_settings_html = crate::handlers::partials::render_lobby_settings_player(game);
 
         drop(games);
 
+        state
+            .broadcast_sse(&game_id, "lobby-players-updated", players_html)
+            .await;
         state
             .broadcast_sse(&game_id, "lobby-settings-host", host_settings_html)
             .await;
@@ -200,6 +410,109 @@ pub async fn configure(
     "OK"
 }
 
+/// Movement and artist checkboxes for building the deck. They repeat their
+/// field names and an unticked box sends nothing, so the raw pairs are read
+/// and each submission replaces all three lists.
+pub async fn configure_deck(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<&'static str, Html<String>> {
+    let values = |name: &str| -> Vec<&str> {
+        fields
+            .iter()
+            .filter(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+            .collect()
+    };
+    let artists = |name: &str| -> Vec<Artist> {
+        let values = values(name);
+        all_artists()
+            .into_iter()
+            .filter(|a| values.contains(&format!("{:?}", a).as_str()))
+            .collect()
+    };
+    let movements = |name: &str| -> Vec<Movement> {
+        let values = values(name);
+        all_movements()
+            .into_iter()
+            .filter(|m| values.contains(&format!("{:?}", m).as_str()))
+            .collect()
+    };
+
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    if !matches!(game.state, GameState::Lobby) {
+        return Err(Html("Game already started".to_string()));
+    }
+
+    game.config.deck_movements = movements("deck_movement");
+    game.config.focus_artists = artists("focus_artist");
+    game.config.focus_movements = movements("focus_movement");
+
+    let host_settings_html =
+        crate::handlers::partials::render_lobby_settings_host(game, &game_id, &game.host_id);
+    let player_settings_html = crate::handlers::partials::render_lobby_settings_player(game);
+
+    drop(games);
+
+    state
+        .broadcast_sse(&game_id, "lobby-settings-host", host_settings_html)
+        .await;
+    state
+        .broadcast_sse(&game_id, "lobby-settings-player", player_settings_html)
+        .await;
+
+    Ok("OK")
+}
+
+#[derive(Deserialize)]
+pub struct TeamForm {
+    player_id: String,
+    team: u8, // 0 takes the player off their team
+}
+
+pub async fn assign_team(
+    Path(game_id): Path<String>,
+    State(state): State<AppState>,
+    Form(form): Form<TeamForm>,
+) -> Result<&'static str, Html<String>> {
+    let mut games = state.games.write().await;
+    let game = match games.get_mut(&game_id) {
+        Some(g) => g,
+        None => return Err(Html("Game not found".to_string())),
+    };
+
+    if !matches!(game.state, GameState::Lobby) {
+        return Err(Html("Game already started".to_string()));
+    }
+
+    let player = match game.players.get_mut(&form.player_id) {
+        Some(p) => p,
+        None => return Err(Html("Player not found".to_string())),
+    };
+    player.team = (form.team > 0).then_some(form.team);
+
+    let players_html = crate::handlers::partials::render_lobby_players_card(game);
+    let host_settings_html =
+        crate::handlers::partials::render_lobby_settings_host(game, &game_id, &game.host_id);
+
+    drop(games);
+
+    state
+        .broadcast_sse(&game_id, "lobby-players-updated", players_html)
+        .await;
+    state
+        .broadcast_sse(&game_id, "lobby-settings-host", host_settings_html)
+        .await;
+
+    Ok("OK")
+}
+
 pub async fn start_game(
     Path(game_id): Path<String>,
     State(state): State<AppState>,
@@ -1,4 +1,9 @@
-use crate::models::Game;
+use crate::models::{
+    Acquisition, ArtPiece, Artist, AuctionType, BlindHides, BlindReveal, ChosenBonus, DeckStrategy,
+    FloorRule, Game, GameConfig, GameMode, IncrementRule, MaskedArt, RoundResult, ScoreBreakdown,
+    ScoreGroup, ScoringMode, SellBackBasis, SellBackDestination, TeamScoring, TieBreaker,
+};
+use crate::services::art_database::{all_artists, all_movements};
 
 /// Render the timer display partial
 pub fn render_timer_partial(game: &Game, player_id: &str, game_id: &str) -> String {
@@ -12,6 +17,22 @@ pub fn render_timer_partial(game: &Game, player_id: &str, game_id: &str) -> Stri
             _ => 0,
         };
 
+        // Nothing to start until the seller has put a painting up
+        if game.current_art.is_empty()
+            && let Some(seller_id) = &game.seller_id
+        {
+            return format!(
+                r#"<div class="text-2xl font-bold text-warning">
+                Waiting for {} to choose a painting...
+            </div>"#,
+                if seller_id == player_id {
+                    "you".to_string()
+                } else {
+                    display_player_name(game, seller_id)
+                }
+            );
+        }
+
         if is_host {
             format!(
                 r#"<form
@@ -33,6 +54,26 @@ pub fn render_timer_partial(game: &Game, player_id: &str, game_id: &str) -> Stri
             </div>"#
                 .to_string()
         }
+    } else if let Some(turn_player_id) = game.current_turn_player() {
+        format!(
+            r#"<div class="text-4xl font-mono font-bold text-center {}">{}</div><p class="text-xs opacity-50 text-center">seconds left for <strong>{}</strong> to act</p>"#,
+            if seconds <= 5 {
+                "text-error"
+            } else {
+                "text-info"
+            },
+            seconds,
+            if turn_player_id == player_id {
+                "you".to_string()
+            } else {
+                display_player_name(game, turn_player_id)
+            }
+        )
+    } else if game.config.auction_type == AuctionType::Dutch {
+        format!(
+            r#"<div class="text-2xl font-bold text-center text-info">⬇️ Price dropping</div><p class="text-xs opacity-50 text-center">Floor: {} chips - first to buy wins</p>"#,
+            game.config.dutch_floor_price
+        )
     } else {
         format!(
             r#"<div class="text-4xl font-mono font-bold text-center {}">{}</div><p class="text-xs opacity-50 text-center">seconds remaining</p>"#,
@@ -48,6 +89,29 @@ pub fn render_timer_partial(game: &Game, player_id: &str, game_id: &str) -> Stri
 
 /// Render the current bid display partial
 pub fn render_bid_partial(game: &Game) -> String {
+    if game.config.auction_type.is_sealed() {
+        // Never leak amounts or bidders before the reveal
+        let bid_count = game.current_bids.len();
+        return format!(
+            r#"<div class="alert">
+                <div class="flex items-center gap-2">
+                    <span>🔒 Sealed bidding: <strong>{} bid{}</strong> submitted. All bids are revealed when the timer runs out.</span>
+                </div>
+            </div>"#,
+            bid_count,
+            if bid_count == 1 { "" } else { "s" }
+        );
+    }
+
+    if game.config.auction_type == AuctionType::Dutch && game.current_bids.is_empty() {
+        return r#"<div class="alert">
+            <div class="flex items-center gap-2">
+                <span>⬇️ No buyer yet - the price keeps falling until someone buys.</span>
+            </div>
+        </div>"#
+            .to_string();
+    }
+
     if let Some(bid) = game.get_highest_bid() {
         let player_name = game
             .players
@@ -75,6 +139,105 @@ pub fn render_bid_partial(game: &Game) -> String {
     }
 }
 
+/// Render the art market board: each artist's value and this season's sales
+pub fn render_market_board_partial(game: &Game) -> String {
+    if !game.config.market_enabled {
+        return String::new();
+    }
+
+    // Blind pieces count towards their artist, but the board can't say which
+    let (season_sales, blind_sales) = game.visible_season_sales();
+    let mut artists: Vec<Artist> = season_sales.iter().map(|(a, _)| *a).collect();
+    for artist in game.market_values.keys() {
+        if !artists.contains(artist) {
+            artists.push(*artist);
+        }
+    }
+    artists.sort_by_key(|a| std::cmp::Reverse(game.market_values.get(a).copied().unwrap_or(0)));
+
+    let mut rows: String = artists
+        .iter()
+        .map(|artist| {
+            let sales = season_sales
+                .iter()
+                .find(|(a, _)| a == artist)
+                .map(|(_, count)| *count)
+                .unwrap_or(0);
+            format!(
+                r#"<tr><td>{}</td><td class="text-right">{}</td><td class="text-right font-semibold">+{}</td></tr>"#,
+                artist.name(),
+                sales,
+                game.market_values.get(artist).copied().unwrap_or(0)
+            )
+        })
+        .collect();
+    if blind_sales > 0 {
+        rows.push_str(&format!(
+            r#"<tr class="opacity-70"><td>🙈 Unrevealed</td><td class="text-right">{}</td><td class="text-right">-</td></tr>"#,
+            blind_sales
+        ));
+    }
+
+    let last_awards = if game.last_season_awards.is_empty() {
+        String::new()
+    } else {
+        format!(
+            r#"<p class="text-xs opacity-70">Last season: {}</p>"#,
+            game.last_season_awards
+                .iter()
+                .map(|(artist, award)| format!("{} +{}", artist.name(), award))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+
+    let season_length = game.config.season_length.max(1);
+    let season_end = game.season * season_length;
+    format!(
+        r#"<div class="card bg-base-100 shadow-xl">
+            <div class="card-body">
+                <h3 class="card-title text-lg">📈 Art Market</h3>
+                <p class="text-xs opacity-70">Season {} - ends after round {}</p>
+                {}
+                <table class="table table-xs">
+                    <thead><tr><th>Artist</th><th class="text-right">Sold</th><th class="text-right">Value</th></tr></thead>
+                    <tbody>{}</tbody>
+                </table>
+            </div>
+        </div>"#,
+        game.season,
+        season_end.min(game.config.num_rounds),
+        last_awards,
+        if rows.is_empty() {
+            r#"<tr><td colspan="3" class="text-center opacity-50">No sales yet</td></tr>"#
+                .to_string()
+        } else {
+            rows
+        }
+    )
+}
+
+/// Render the falling asking price of a Dutch round
+pub fn render_price_ticker_partial(game: &Game) -> String {
+    match game.current_price {
+        Some(price) if game.config.auction_type == AuctionType::Dutch => format!(
+            r#"<div class="stat bg-base-200 rounded-lg mb-4 text-center">
+                <div class="stat-title">Current Price</div>
+                <div class="stat-value font-mono {}">{}</div>
+                <div class="stat-desc">Drops {} every second</div>
+            </div>"#,
+            if price <= game.config.dutch_floor_price {
+                "text-error"
+            } else {
+                "text-primary"
+            },
+            price,
+            game.config.dutch_price_step
+        ),
+        _ => String::new(),
+    }
+}
+
 /// Render the player list partial for lobby (matches template structure)
 pub fn render_player_list_partial(game: &Game) -> String {
     let mut html = String::new();
@@ -93,6 +256,12 @@ pub fn render_player_list_partial(game: &Game) -> String {
         } else {
             ""
         };
+        let team_badge = match player.team {
+            Some(team) if game.config.teams_enabled => {
+                format!(r#"<div class="badge badge-secondary">Team {}</div>"#, team)
+            }
+            _ => String::new(),
+        };
 
         html.push_str(&format!(
             r#"<div class="flex items-center gap-2 p-3 bg-base-200 rounded-lg">
@@ -105,8 +274,9 @@ pub fn render_player_list_partial(game: &Game) -> String {
                     <div class="font-semibold">{}</div>
                 </div>
                 {}
+                {}
             </div>"#,
-            first_char, player.name, host_badge
+            first_char, player.name, team_badge, host_badge
         ));
     }
 
@@ -129,10 +299,46 @@ pub fn render_player_info_partial(
     player_name: &str,
 ) -> String {
     if let Some(player) = game.players.get(player_id) {
+        let hand_desc = if game.config.game_mode == GameMode::PlayerAuctioneer {
+            format!(
+                r#"<div class="stat-desc">{} painting{} in hand</div>"#,
+                player.hand.len(),
+                if player.hand.len() == 1 { "" } else { "s" }
+            )
+        } else {
+            String::new()
+        };
+
+        // Only this player ever sees their objective before the game ends
+        let objective_html = match &player.objective {
+            Some(objective) => format!(
+                r#"<div class="stat-desc" title="Secret objective">🎯 {} (+{}) {}</div>"#,
+                objective.description,
+                objective.bonus,
+                if objective.is_met(player) { "✔" } else { "" }
+            ),
+            None => String::new(),
+        };
+
+        let loan_html = if game.config.loans_enabled {
+            render_loan_controls(game, player)
+        } else {
+            String::new()
+        };
+
+        let team_html = if game.config.teams_enabled {
+            render_team_controls(game, player)
+        } else {
+            String::new()
+        };
+
         format!(
             r#"<div class="stat bg-base-100 rounded-lg">
                 <div class="stat-title">Your Chips</div>
                 <div class="stat-value text-primary">{}</div>
+                {}
+                {}
+                {}
             </div>
             <div>
                 <h1 class="text-2xl font-bold">Round {}</h1>
@@ -142,26 +348,121 @@ pub fn render_player_info_partial(
                 <div class="stat-title">Collection</div>
                 <div class="stat-value text-secondary">{}</div>
                 <div class="stat-desc">Score: {}</div>
+                {}
             </div>"#,
             player.chips,
+            hand_desc,
+            loan_html,
+            team_html,
             round,
             player_name,
             player.collection.len(),
-            player.calculate_score()
+            game.final_score(player),
+            objective_html
         )
     } else {
         r#"<div class="alert alert-error">Player not found</div>"#.to_string()
     }
 }
 
+/// Outstanding debt plus borrow/repay buttons for the player info panel
+fn render_loan_controls(game: &Game, player: &crate::models::Player) -> String {
+    const LOAN_STEP: u32 = 10;
+
+    let debt_desc = if player.debt > 0 {
+        format!(
+            r#"<div class="stat-desc text-error">Debt: {} chips (-{} pts if unpaid)</div>"#,
+            player.debt,
+            player.debt_penalty()
+        )
+    } else {
+        r#"<div class="stat-desc">No debt</div>"#.to_string()
+    };
+
+    format!(
+        r#"{}
+                <div class="stat-actions flex gap-1">
+                    <button class="btn btn-xs btn-outline" hx-post="/game/{}/borrow" hx-vals='{{"player_id": "{}", "amount": {}}}' hx-swap="none" {}>Borrow {}</button>
+                    <button class="btn btn-xs btn-outline" hx-post="/game/{}/repay" hx-vals='{{"player_id": "{}", "amount": {}}}' hx-swap="none" {}>Repay {}</button>
+                </div>"#,
+        debt_desc,
+        game.id,
+        player.id,
+        LOAN_STEP,
+        if player.debt + LOAN_STEP > game.config.loan_limit {
+            "disabled"
+        } else {
+            ""
+        },
+        LOAN_STEP,
+        game.id,
+        player.id,
+        player.debt.min(LOAN_STEP),
+        if player.debt == 0 || player.chips < player.debt.min(LOAN_STEP) as i32 {
+            "disabled"
+        } else {
+            ""
+        },
+        player.debt.min(LOAN_STEP)
+    )
+}
+
+/// Teammates plus a form to send them chips, for the player info panel
+fn render_team_controls(game: &Game, player: &crate::models::Player) -> String {
+    let Some(team) = player.team else {
+        return String::new();
+    };
+    let teammates: Vec<&crate::models::Player> = game
+        .team_members(team)
+        .into_iter()
+        .filter(|p| p.id != player.id)
+        .collect();
+    let names: Vec<&str> = teammates.iter().map(|p| p.name.as_str()).collect();
+
+    let transfer_form = if teammates.is_empty() {
+        String::new()
+    } else {
+        let options: String = teammates
+            .iter()
+            .map(|p| format!(r#"<option value="{}">{}</option>"#, p.id, p.name))
+            .collect();
+        format!(
+            r#"<form class="stat-actions flex gap-1" hx-post="/game/{}/transfer" hx-swap="none">
+                    <input type="hidden" name="player_id" value="{}" />
+                    <select name="teammate_id" class="select select-bordered select-xs">{}</select>
+                    <input type="number" name="amount" min="1" max="{}" value="5" class="input input-bordered input-xs w-16" />
+                    <button type="submit" class="btn btn-xs btn-outline" {}>Send chips</button>
+                </form>"#,
+            game.id,
+            player.id,
+            options,
+            player.chips.max(1),
+            if player.chips > 0 { "" } else { "disabled" }
+        )
+    };
+
+    format!(
+        r#"<div class="stat-desc">👥 Team {}{}</div>
+                {}"#,
+        team,
+        if names.is_empty() {
+            String::new()
+        } else {
+            format!(" with {}", names.join(", "))
+        },
+        transfer_form
+    )
+}
+
 /// Render the current art piece display - matches template structure
 pub fn render_current_art_partial(game: &Game) -> String {
-    if let Some(art) = &game.current_art {
-        let stars: String = (0..art.stars).map(|_| "⭐").collect();
+    match game.current_art.as_slice() {
+        [art] => {
+            let art = game.masked_art(art, None);
 
-        format!(
-            r#"<div>
-                <h2 class="card-title text-2xl">{}</h2>
+            format!(
+                r#"<div>
+                <h2 class="card-title text-2xl">{}{}</h2>
                 <p class="text-lg opacity-70">{}</p>
                 <p class="text-sm opacity-50">{}</p>
             </div>
@@ -171,29 +472,155 @@ pub fn render_current_art_partial(game: &Game) -> String {
                 </div>
                 <p class="text-xs opacity-50">Value</p>
             </div>"#,
-            art.name,
-            art.artist.name(),
-            art.movement.name(),
-            stars
-        )
+                art.name,
+                blind_badge(&art),
+                art.artist_name(),
+                art.movement_name(),
+                art.star_display()
+            )
+        }
+        [] => {
+            if let Some(seller_id) = &game.seller_id {
+                format!(
+                    r#"<div class="alert alert-info">🔨 {} is choosing a painting to auction...</div>"#,
+                    display_player_name(game, seller_id)
+                )
+            } else {
+                r#"<div class="alert alert-info">No art piece available</div>"#.to_string()
+            }
+        }
+        lot => {
+            // Multi-piece lot: the winner takes every piece
+            let mut rows = String::new();
+            let lot: Vec<MaskedArt> = lot.iter().map(|a| game.masked_art(a, None)).collect();
+            for art in &lot {
+                rows.push_str(&format!(
+                    r#"<div class="flex justify-between items-start bg-base-200 rounded-lg p-3">
+                    <div>
+                        <h3 class="font-semibold text-lg">{}{}</h3>
+                        <p class="opacity-70">{}</p>
+                        <p class="text-sm opacity-50">{}</p>
+                    </div>
+                    <div class="text-xl">{}</div>
+                </div>"#,
+                    art.name,
+                    blind_badge(art),
+                    art.artist_name(),
+                    art.movement_name(),
+                    art.star_display()
+                ));
+            }
+
+            // Hidden star values stay out of the total
+            let total_stars: u32 = lot.iter().filter_map(|a| a.stars).map(u32::from).sum();
+            let total = if lot.iter().any(|a| a.stars.is_none()) {
+                format!("{}⭐ + ❓", total_stars)
+            } else {
+                format!("{}⭐ total", total_stars)
+            };
+            format!(
+                r#"<div class="w-full space-y-2">
+                <div class="flex justify-between items-center">
+                    <h2 class="card-title text-2xl">📦 Lot of {} pieces</h2>
+                    <span class="badge badge-lg">{}</span>
+                </div>
+                {}
+            </div>"#,
+                lot.len(),
+                total,
+                rows
+            )
+        }
+    }
+}
+
+/// Badge marking a painting auctioned with attributes hidden
+fn blind_badge(art: &MaskedArt) -> &'static str {
+    if art.is_blind {
+        r#" <span class="badge badge-warning align-middle">🙈 Blind lot</span>"#
     } else {
-        r#"<div class="alert alert-info">No art piece available</div>"#.to_string()
+        ""
+    }
+}
+
+/// Render the next paintings in the deck for the "coming up" panel
+pub fn render_upcoming_lots_partial(game: &Game) -> String {
+    let upcoming = game.upcoming_art();
+    if upcoming.is_empty() {
+        return r#"<p class="text-center opacity-50 py-4">Nothing left in the deck</p>"#
+            .to_string();
     }
+
+    let rows: String = upcoming
+        .iter()
+        .map(|(lot, art)| {
+            let art = game.masked_art(art, None);
+            format!(
+                r#"<div class="flex justify-between items-center p-2 bg-base-200 rounded-lg">
+                <div>
+                    <div class="font-semibold text-sm">{}</div>
+                    <div class="text-xs opacity-70">🎨 {} · 🏛️ {}</div>
+                </div>
+                <div class="text-right">
+                    <div class="text-xs">{}</div>
+                    <div class="text-xs opacity-50">{}</div>
+                </div>
+            </div>"#,
+                art.name,
+                art.artist_name(),
+                art.movement_name(),
+                art.star_display(),
+                if *lot == 1 {
+                    "Next round".to_string()
+                } else {
+                    format!("In {} rounds", lot)
+                }
+            )
+        })
+        .collect();
+
+    format!(r#"<div class="space-y-2">{}</div>"#, rows)
 }
 
 /// Render the collection display for the sidebar
-pub fn render_collection_display_partial(player: &crate::models::Player) -> String {
+pub fn render_collection_display_partial(game: &Game, player: &crate::models::Player) -> String {
     let collection_count = player.collection.len();
-    let collection_score = player.calculate_score();
+    let collection_score = game.final_score(player);
 
     if collection_count > 0 {
         let mut html = String::from(r#"<div class="space-y-2 max-h-96 overflow-y-auto">"#);
 
-        // Add each art piece
+        // Pieces won together in one lot are shown as a group
+        let mut lots: Vec<(Option<&Acquisition>, Vec<&ArtPiece>)> = Vec::new();
         for art in &player.collection {
-            let stars: String = (0..art.stars).map(|_| "⭐").collect();
-            html.push_str(&format!(
-                r#"<div class="card bg-base-200 shadow-sm">
+            let acquisition = player.acquisitions.get(&art.id);
+            match lots.last_mut() {
+                Some((Some(last), pieces))
+                    if acquisition.is_some_and(|a| a.round == last.round && a.lot_size > 1) =>
+                {
+                    pieces.push(art)
+                }
+                _ => lots.push((acquisition, vec![art])),
+            }
+        }
+
+        for (acquisition, pieces) in lots {
+            let mut cards = String::new();
+            for art in &pieces {
+                let stars: String = (0..art.stars).map(|_| "⭐").collect();
+                let sell_button = if game.config.sell_back_enabled {
+                    format!(
+                        r#"<button class="btn btn-xs btn-outline mt-1" hx-post="/game/{}/sell" hx-vals='{{"player_id": "{}", "art_id": {}}}' hx-swap="none">Sell to bank for {}</button>"#,
+                        game.id,
+                        player.id,
+                        art.id,
+                        game.sell_back_price(player, art)
+                    )
+                } else {
+                    String::new()
+                };
+                cards.push_str(&format!(
+                    r#"<div class="card bg-base-200 shadow-sm">
                     <div class="card-body p-3">
                         <h4 class="font-semibold text-sm">{}</h4>
                         <div class="text-xs opacity-70 space-y-1">
@@ -201,13 +628,29 @@ pub fn render_collection_display_partial(player: &crate::models::Player) -> Stri
                             <div>🎨 {}</div>
                             <div>🏛️ {}</div>
                         </div>
+                        {}
+                        {}
                     </div>
                 </div>"#,
-                art.name,
-                stars,
-                art.artist.name(),
-                art.movement.name()
-            ));
+                    art.name,
+                    stars,
+                    art.artist.name(),
+                    art.movement.name(),
+                    render_authentication(game, player, art),
+                    sell_button
+                ));
+            }
+
+            match acquisition {
+                Some(acquisition) if pieces.len() > 1 => html.push_str(&format!(
+                    r#"<div class="border border-base-300 rounded-lg p-2 space-y-2">
+                    <div class="text-xs font-semibold opacity-70">📦 Round {} lot · {} chips</div>
+                    {}
+                </div>"#,
+                    acquisition.round, acquisition.price, cards
+                )),
+                _ => html.push_str(&cards),
+            }
         }
 
         // Add score summary at the bottom
@@ -215,10 +658,17 @@ pub fn render_collection_display_partial(player: &crate::models::Player) -> Stri
             r#"<div class="stat bg-base-300 rounded-lg mt-2">
                 <div class="stat-title">Total Score</div>
                 <div class="stat-value text-sm text-primary">{}</div>
-                <div class="stat-desc">With bonuses</div>
+                <div class="stat-desc">{} scoring</div>
             </div>
+            <details class="collapse collapse-arrow bg-base-200 rounded-lg">
+                <summary class="collapse-title text-sm font-semibold">How is this scored?</summary>
+                <div class="collapse-content">{}{}</div>
+            </details>
         </div>"#,
-            collection_score
+            collection_score,
+            game.config.scoring.rule().name(),
+            render_score_breakdown(&player.score_breakdown(game.config.scoring)),
+            render_score_adjustments(game, player)
         ));
 
         html
@@ -227,123 +677,816 @@ pub fn render_collection_display_partial(player: &crate::models::Player) -> Stri
     }
 }
 
-/// Render the bidding form area
-pub fn render_bidding_area_partial(game: &Game, player_id: &str, game_id: &str) -> String {
-    let timer_seconds = game.calculate_remaining_seconds();
-
-    let player = match game.players.get(player_id) {
-        Some(p) => p,
-        None => return r#"<div class="alert alert-error">Player not found</div>"#.to_string(),
-    };
-
-    let player_chips = player.chips;
+/// Everything the total adds to or takes from the collection score
+fn render_score_adjustments(game: &Game, player: &crate::models::Player) -> String {
+    let lines: String = [
+        ("📈 Market value", game.market_bonus(player) as i64),
+        ("🪙 Leftover chips", game.chip_points(player) as i64),
+        ("🏦 Unpaid debt", -(player.debt_penalty() as i64)),
+        ("🚨 Forgeries", -(game.forgery_penalty(player) as i64)),
+    ]
+    .iter()
+    .filter(|(_, points)| *points != 0)
+    .map(|(label, points)| {
+        format!(
+            r#"<tr><td>{}</td><td class="text-right">{:+}</td></tr>"#,
+            label, points
+        )
+    })
+    .collect();
 
-    if timer_seconds < 0 {
-        return r#"<div class="alert alert-info">
-            <span>⏸️ Waiting for host to start bidding...</span>
-        </div>"#
-            .to_string();
+    if lines.is_empty() {
+        return String::new();
     }
 
-    if player_chips <= 0 {
-        return r#"<div class="alert alert-warning">
-            <span>You have no chips left! You can only watch.</span>
-        </div>"#
-            .to_string();
-    }
+    format!(
+        r#"<table class="table table-xs mt-2"><tbody>{}</tbody></table>"#,
+        lines
+    )
+}
+
+/// Render the per-artist and per-movement score tables, marking the bonus that counted
+pub fn render_score_breakdown(breakdown: &ScoreBreakdown) -> String {
+    let table = |title: &str, groups: &[ScoreGroup], total: u32, counted: bool| {
+        let rows: String = groups
+            .iter()
+            .map(|g| {
+                format!(
+                    r#"<tr><td>{}</td><td>{}⭐ × {}</td><td class="text-right">{}</td></tr>"#,
+                    g.name, g.stars, g.count, g.subtotal
+                )
+            })
+            .collect();
+        format!(
+            r#"<table class="table table-xs {}">
+                <thead><tr><th colspan="2">{}</th><th class="text-right">{}{}</th></tr></thead>
+                <tbody>{}</tbody>
+            </table>"#,
+            if counted { "" } else { "opacity-50" },
+            title,
+            if counted { "✔ " } else { "" },
+            total,
+            rows
+        )
+    };
 
-    let highest_bid = game.get_highest_bid();
-    let has_bid = highest_bid.is_some();
-    let current_highest_bid = highest_bid.as_ref().map(|b| b.amount).unwrap_or(0);
-    let min_bid = if has_bid { current_highest_bid + 1 } else { 1 };
+    let (artist_counted, movement_counted) = match breakdown.chosen {
+        ChosenBonus::Artist => (true, false),
+        ChosenBonus::Movement => (false, true),
+        ChosenBonus::Both => (true, true),
+        ChosenBonus::Neither => (false, false),
+    };
 
     format!(
-        r#"<form
-            hx-post="/game/{}/bid"
-            hx-swap="none"
-            class="flex gap-2"
-        >
-            <input type="hidden" name="player_id" value="{}" />
-            <input
-                type="number"
-                name="amount"
-                placeholder="Enter bid amount"
-                class="input input-bordered flex-1"
-                min="{}"
-                max="{}"
-                required
-            />
-            <button type="submit" class="btn btn-primary">
-                Place Bid
-            </button>
-            <button
-                type="button"
-                class="btn btn-ghost"
-                hx-post="/game/{}/pass"
-                hx-vals='{{"player_id": "{}"}}'
-                hx-swap="none"
-            >
-                Pass
-            </button>
-        </form>
-        <div class="flex gap-2 mt-2">
-            <button
-                type="button"
-                class="btn btn-sm btn-outline"
-                onclick="var input = document.querySelector('input[name=amount]'); input.value = {};"
-            >
-                Min ({})
-            </button>
-            <button
-                type="button"
-                class="btn btn-sm btn-outline"
-                onclick="var input = document.querySelector('input[name=amount]'); var current = parseInt(input.value) || 0; input.value = Math.min(Math.max(current + 1, {}), {});"
-            >
-                +1
-            </button>
-            <button
-                type="button"
-                class="btn btn-sm btn-outline"
-                onclick="var input = document.querySelector('input[name=amount]'); var current = parseInt(input.value) || 0; input.value = Math.min(Math.max(current + 5, {}), {});"
-            >
-                +5
-            </button>
-            <button
-                type="button"
-                class="btn btn-sm btn-outline"
-                onclick="var input = document.querySelector('input[name=amount]'); var current = parseInt(input.value) || 0; input.value = Math.min(Math.max(current + 10, {}), {});"
-            >
-                +10
-            </button>
+        r#"<div class="space-y-2 text-sm">
+            {}
+            {}
+            <div class="flex justify-between font-semibold">
+                <span>{} · {}</span>
+                <span>{}</span>
+            </div>
         </div>"#,
-        game_id,
-        player_id,
-        min_bid,
-        player_chips,
-        game_id,
-        player_id,
-        min_bid,
-        min_bid,
-        min_bid,
-        player_chips,
-        min_bid,
-        player_chips,
-        min_bid,
-        player_chips
+        table(
+            "🎨 Artists",
+            &breakdown.artists,
+            breakdown.artist_total,
+            artist_counted
+        ),
+        table(
+            "🏛️ Movements",
+            &breakdown.movements,
+            breakdown.movement_total,
+            movement_counted
+        ),
+        breakdown.rule,
+        breakdown.chosen.name(),
+        breakdown.total
     )
 }
 
-/// Render a simple trigger message for game-started
-/// The actual redirect is handled by the page's hidden trigger element
-pub fn render_game_started_trigger() -> String {
-    // Just send any content - the SSE event name "game-started" is what matters
-    // The page has a hidden div with hx-trigger="sse:game-started" that will handle the redirect
-    String::from("<!-- Game starting -->")
-}
-
-/// Render a simple trigger message for game-finished
-pub fn render_game_finished_trigger() -> String {
-    // Just send any content - the SSE event name "game-finished" is what matters
+/// The verdict on a painting the player had checked, or a button to check it
+fn render_authentication(game: &Game, player: &crate::models::Player, art: &ArtPiece) -> String {
+    if player.authenticated.contains(&art.id) {
+        if game.is_forgery(art.id) {
+            r#"<div class="badge badge-error badge-sm">🚫 Forgery</div>"#.to_string()
+        } else {
+            r#"<div class="badge badge-success badge-sm">✅ Authentic</div>"#.to_string()
+        }
+    } else if game.config.authentication_enabled {
+        format!(
+            r#"<button class="btn btn-xs btn-ghost mt-1" hx-post="/game/{}/authenticate" hx-vals='{{"player_id": "{}", "art_id": {}}}' hx-swap="none">🔍 Authenticate for {}</button>"#,
+            game.id, player.id, art.id, game.config.authentication_cost
+        )
+    } else {
+        String::new()
+    }
+}
+
+/// Render the seller's hand so they can pick a painting to auction
+fn render_seller_hand(player: &crate::models::Player, game_id: &str) -> String {
+    let mut cards = String::new();
+    for art in &player.hand {
+        let stars: String = (0..art.stars).map(|_| "⭐").collect();
+        cards.push_str(&format!(
+            r#"<form hx-post="/game/{}/offer" hx-swap="none" class="card bg-base-200 shadow-sm">
+                <input type="hidden" name="player_id" value="{}" />
+                <input type="hidden" name="art_id" value="{}" />
+                <div class="card-body p-3 flex-row justify-between items-center">
+                    <div>
+                        <h4 class="font-semibold text-sm">{}</h4>
+                        <div class="text-xs opacity-70">{} · 🎨 {} · 🏛️ {}</div>
+                    </div>
+                    <button type="submit" class="btn btn-sm btn-primary">Auction this</button>
+                </div>
+            </form>"#,
+            game_id,
+            player.id,
+            art.id,
+            art.name,
+            stars,
+            art.artist.name(),
+            art.movement.name()
+        ));
+    }
+
+    format!(
+        r#"<div class="space-y-2">
+            <p class="font-semibold">🔨 Your turn to sell! Pick a painting from your hand:</p>
+            {}
+        </div>"#,
+        cards
+    )
+}
+
+/// Render the once-around seating for this round, showing who bid, passed or is up
+fn render_turn_order(game: &Game) -> String {
+    let seats = game.turn_order.len();
+    if seats == 0 {
+        return String::new();
+    }
+
+    let round = match game.state {
+        crate::models::GameState::Active { round, .. } => round,
+        _ => 1,
+    };
+    let start = round.saturating_sub(1) % seats;
+    let current = game.current_turn_player();
+
+    let mut badges = String::new();
+    for offset in 0..seats {
+        let seat_player_id = &game.turn_order[(start + offset) % seats];
+        let (badge_class, icon) = if game.is_seller(seat_player_id) {
+            ("badge-secondary", "🔨")
+        } else if Some(seat_player_id) == current {
+            ("badge-primary", "⏳")
+        } else if game.passed_players.contains(seat_player_id) {
+            ("badge-ghost", "✖")
+        } else if game.get_player_bid(seat_player_id).is_some() {
+            ("badge-success", "✔")
+        } else {
+            ("badge-outline", "")
+        };
+        badges.push_str(&format!(
+            r#"<span class="badge {}">{} {}</span>"#,
+            badge_class,
+            icon,
+            display_player_name(game, seat_player_id)
+        ));
+    }
+
+    format!(
+        r#"<div class="flex flex-wrap gap-2 mt-2 text-sm">{}</div>"#,
+        badges
+    )
+}
+
+/// Render the players who have passed on the current piece
+fn render_passed_players(game: &Game) -> String {
+    if game.passed_players.is_empty() {
+        return String::new();
+    }
+
+    let mut names: Vec<String> = game
+        .passed_players
+        .iter()
+        .map(|player_id| display_player_name(game, player_id))
+        .collect();
+    names.sort();
+
+    let badges: String = names
+        .iter()
+        .map(|name| format!(r#"<span class="badge badge-ghost">✖ {}</span>"#, name))
+        .collect();
+
+    format!(
+        r#"<div class="flex flex-wrap items-center gap-2 mt-2 text-sm">
+            <span class="opacity-70">Passed:</span>{}
+        </div>"#,
+        badges
+    )
+}
+
+/// Render the reveal of the last resolved round, listing every bid placed
+pub fn render_round_reveal_partial(game: &Game) -> String {
+    let result = match &game.last_round {
+        Some(r) => r,
+        None => return String::new(),
+    };
+
+    if result.auction_type == AuctionType::SealedSecondPrice {
+        return render_second_price_reveal_partial(game, result);
+    }
+
+    let art_name = lot_title(game, &result.lot);
+
+    let headline = match (&result.winner_id, &result.seller_id) {
+        (Some(winner_id), Some(seller_id)) => format!(
+            "<strong>{}</strong> won <em>{}</em> for <strong>{} chips</strong>, paid to <strong>{}</strong>",
+            display_player_name(game, winner_id),
+            art_name,
+            result.price_paid,
+            display_player_name(game, seller_id)
+        ),
+        (Some(winner_id), None) => format!(
+            "<strong>{}</strong> won <em>{}</em> for <strong>{} chips</strong>",
+            display_player_name(game, winner_id),
+            art_name,
+            result.price_paid
+        ),
+        (None, Some(seller_id)) => format!(
+            "Nobody bid on <em>{}</em> - <strong>{}</strong> keeps it for free",
+            art_name,
+            display_player_name(game, seller_id)
+        ),
+        (None, None) if result.reserved => format!(
+            "<em>{}</em> hit the floor price unsold - it will be auctioned again at the end",
+            art_name
+        ),
+        (None, None) if result.reserve_price > 0 => format!(
+            "<em>{}</em> didn't reach its reserve of <strong>{} chips</strong> - unsold and discarded",
+            art_name, result.reserve_price
+        ),
+        (None, None) => format!("Nobody bid on <em>{}</em> - it was discarded", art_name),
+    };
+
+    format!(
+        r#"<div class="card bg-base-200">
+            <div class="card-body p-4">
+                <h3 class="font-semibold">Round {} Reveal - {}</h3>
+                <p class="text-sm">{}</p>
+                {}
+            </div>
+        </div>"#,
+        result.round,
+        result.auction_type.name(),
+        headline,
+        render_revealed_bids_table(game, result)
+    )
+}
+
+/// Render the second-price reveal: the winning bid next to the price actually paid
+fn render_second_price_reveal_partial(game: &Game, result: &RoundResult) -> String {
+    let art_name = lot_title(game, &result.lot);
+
+    let summary = match (&result.winner_id, result.bids.first()) {
+        (Some(winner_id), Some(winning_bid)) => format!(
+            r#"<p class="text-sm"><strong>{}</strong> won <em>{}</em>{}</p>
+                <div class="stats stats-horizontal bg-base-100 shadow-sm">
+                    <div class="stat p-3">
+                        <div class="stat-title">Winning Bid</div>
+                        <div class="stat-value text-lg">{}</div>
+                    </div>
+                    <div class="stat p-3">
+                        <div class="stat-title">Price Paid</div>
+                        <div class="stat-value text-lg text-primary">{}</div>
+                        <div class="stat-desc">{}</div>
+                    </div>
+                </div>"#,
+            display_player_name(game, winner_id),
+            art_name,
+            result
+                .seller_id
+                .as_ref()
+                .map(|seller_id| format!(
+                    " from <strong>{}</strong>",
+                    display_player_name(game, seller_id)
+                ))
+                .unwrap_or_default(),
+            winning_bid.amount,
+            result.price_paid,
+            if result.bids.len() > 1 {
+                "Second-highest bid"
+            } else {
+                "Only bidder - minimum price"
+            }
+        ),
+        _ => match &result.seller_id {
+            Some(seller_id) => format!(
+                r#"<p class="text-sm">Nobody bid on <em>{}</em> - <strong>{}</strong> keeps it for free</p>"#,
+                art_name,
+                display_player_name(game, seller_id)
+            ),
+            None if result.reserve_price > 0 => format!(
+                r#"<p class="text-sm"><em>{}</em> didn't reach its reserve of <strong>{} chips</strong> - unsold and discarded</p>"#,
+                art_name, result.reserve_price
+            ),
+            None => format!(
+                r#"<p class="text-sm">Nobody bid on <em>{}</em> - it was discarded</p>"#,
+                art_name
+            ),
+        },
+    };
+
+    format!(
+        r#"<div class="card bg-base-200">
+            <div class="card-body p-4">
+                <h3 class="font-semibold">Round {} Reveal - {}</h3>
+                {}
+                {}
+            </div>
+        </div>"#,
+        result.round,
+        result.auction_type.name(),
+        summary,
+        render_revealed_bids_table(game, result)
+    )
+}
+
+/// Render every bid of a resolved round, highlighting the winner
+fn render_revealed_bids_table(game: &Game, result: &RoundResult) -> String {
+    if result.bids.is_empty() {
+        return String::new();
+    }
+
+    let mut rows = String::new();
+    for bid in &result.bids {
+        let is_winner = result.winner_id.as_deref() == Some(bid.player_id.as_str());
+        rows.push_str(&format!(
+            r#"<tr class="{}">
+                    <td>{}</td>
+                    <td class="text-right font-mono">{}</td>
+                </tr>"#,
+            if is_winner {
+                "font-bold text-primary"
+            } else {
+                ""
+            },
+            display_player_name(game, &bid.player_id),
+            bid.amount
+        ));
+    }
+
+    format!(
+        r#"<table class="table table-xs">
+                    <thead><tr><th>Player</th><th class="text-right">Bid</th></tr></thead>
+                    <tbody>{}</tbody>
+                </table>"#,
+        rows
+    )
+}
+
+/// Name a lot by its pieces, e.g. "Sunflowers & The Scream"
+fn lot_title(game: &Game, lot: &[ArtPiece]) -> String {
+    let names: Vec<&str> = lot.iter().map(|a| game.masked_art(a, None).name).collect();
+    match names.as_slice() {
+        [] => "Unknown piece".to_string(),
+        [name] => name.to_string(),
+        [rest @ .., last] => format!("{} & {}", rest.join(", "), last),
+    }
+}
+
+/// Look up a player's display name, falling back to their id
+fn display_player_name(game: &Game, player_id: &str) -> String {
+    game.players
+        .get(player_id)
+        .map(|p| p.name.clone())
+        .unwrap_or_else(|| player_id.to_string())
+}
+
+/// Render the bidding form area
+pub fn render_bidding_area_partial(game: &Game, player_id: &str, game_id: &str) -> String {
+    let timer_seconds = game.calculate_remaining_seconds();
+
+    let player = match game.players.get(player_id) {
+        Some(p) => p,
+        None => return r#"<div class="alert alert-error">Player not found</div>"#.to_string(),
+    };
+
+    let player_chips = player.chips;
+
+    if timer_seconds < 0 {
+        if game.is_seller(player_id) && game.current_art.is_empty() {
+            return render_seller_hand(player, game_id);
+        }
+
+        return r#"<div class="alert alert-info">
+            <span>⏸️ Waiting for host to start bidding...</span>
+        </div>"#
+            .to_string();
+    }
+
+    if game.is_seller(player_id) {
+        return r#"<div class="alert alert-success">
+            <span>🔨 You're auctioning this painting - the winning bid is paid to you. If nobody bids, you keep it.</span>
+        </div>"#
+            .to_string();
+    }
+
+    if player_chips <= 0 {
+        if game.config.round_stipend > 0 {
+            return format!(
+                r#"<div class="alert alert-warning">
+            <span>You have no chips left! You'll get {} more at the start of next round.</span>
+        </div>"#,
+                game.config.round_stipend
+            );
+        }
+
+        return r#"<div class="alert alert-warning">
+            <span>You have no chips left! You can only watch.</span>
+        </div>"#
+            .to_string();
+    }
+
+    if game.config.auction_type == AuctionType::Dutch {
+        return format!(
+            r#"<form
+            hx-post="/game/{}/buy"
+            hx-swap="none"
+            class="flex gap-2"
+        >
+            <input type="hidden" name="player_id" value="{}" />
+            <button type="submit" class="btn btn-primary btn-lg flex-1">
+                🛒 Buy at Current Price
+            </button>
+        </form>"#,
+            game_id, player_id
+        );
+    }
+
+    if game.config.auction_type == AuctionType::OnceAround {
+        let turn_order = render_turn_order(game);
+        match game.current_turn_player() {
+            Some(turn_player_id) if turn_player_id == player_id => {}
+            Some(turn_player_id) => {
+                return format!(
+                    r#"<div class="alert alert-info">
+            <span>⏳ Waiting for <strong>{}</strong> to raise or pass...</span>
+        </div>{}"#,
+                    display_player_name(game, turn_player_id),
+                    turn_order
+                );
+            }
+            None => return turn_order,
+        }
+    }
+
+    if game.passed_players.contains(player_id) {
+        return format!(
+            r#"<div class="alert">
+            <span>✖ You passed on this piece. Waiting for the round to finish...</span>
+        </div>{}"#,
+            render_passed_players(game)
+        );
+    }
+
+    if game.config.auction_type.is_sealed()
+        && let Some(bid) = game.get_player_bid(player_id)
+    {
+        return format!(
+            r#"<div class="alert alert-success">
+            <span>🔒 Your sealed bid of <strong>{} chips</strong> is in. Waiting for the reveal...</span>
+        </div>"#,
+            bid.amount
+        );
+    }
+
+    // Quick raises scale with the configured increment (+1/+5/+10 by default)
+    let min_bid = game.minimum_bid();
+    let step = if game.config.auction_type.is_sealed() {
+        game.bid_increment(min_bid)
+    } else {
+        game.get_highest_bid()
+            .map(|b| game.bid_increment(b.amount))
+            .unwrap_or_else(|| game.bid_increment(min_bid))
+    };
+    let quick_bids: String = [1, 5, 10]
+        .iter()
+        .map(|multiple| {
+            format!(
+                r#"<button
+                type="button"
+                class="btn btn-sm btn-outline"
+                onclick="var input = document.querySelector('input[name=amount]'); var current = parseInt(input.value) || 0; input.value = Math.min(Math.max(current + {}, {}), {});"
+            >
+                +{}
+            </button>"#,
+                step * multiple,
+                min_bid,
+                player_chips,
+                step * multiple
+            )
+        })
+        .collect();
+
+    let proxy_form = if game.config.auction_type == AuctionType::Open {
+        render_proxy_bid_form(game, player_id, game_id, min_bid)
+    } else {
+        String::new()
+    };
+
+    let reserve_price = game.reserve_price();
+    let reserve_note = if reserve_price > 0 {
+        format!(
+            r#"<p class="text-xs opacity-60 mt-2">Reserve price: {} chips</p>"#,
+            reserve_price
+        )
+    } else {
+        String::new()
+    };
+
+    // Sealed bidders simply don't bid, so only open and once-around rounds can pass
+    let pass_button = if matches!(
+        game.config.auction_type,
+        AuctionType::Open | AuctionType::OnceAround
+    ) {
+        format!(
+            r#"<button
+                type="button"
+                class="btn btn-ghost"
+                hx-post="/game/{}/pass"
+                hx-vals='{{"player_id": "{}"}}'
+                hx-swap="none"
+            >
+                Pass
+            </button>"#,
+            game_id, player_id
+        )
+    } else {
+        String::new()
+    };
+
+    let round_status = if game.config.auction_type == AuctionType::OnceAround {
+        render_turn_order(game)
+    } else {
+        render_passed_players(game)
+    };
+
+    format!(
+        r#"<form
+            hx-post="/game/{}/bid"
+            hx-swap="none"
+            class="flex gap-2"
+        >
+            <input type="hidden" name="player_id" value="{}" />
+            <input
+                type="number"
+                name="amount"
+                placeholder="Enter bid amount"
+                class="input input-bordered flex-1"
+                min="{}"
+                max="{}"
+                required
+            />
+            <button type="submit" class="btn btn-primary">
+                Place Bid
+            </button>
+            {}
+        </form>
+        <div class="flex gap-2 mt-2">
+            <button
+                type="button"
+                class="btn btn-sm btn-outline"
+                onclick="var input = document.querySelector('input[name=amount]'); input.value = {};"
+            >
+                Min ({})
+            </button>
+            {}
+        </div>
+        {}
+        {}
+        {}"#,
+        game_id,
+        player_id,
+        min_bid,
+        player_chips,
+        pass_button,
+        min_bid,
+        min_bid,
+        quick_bids,
+        proxy_form,
+        reserve_note,
+        round_status
+    )
+}
+
+/// Render the hidden maximum bid form for open auctions
+fn render_proxy_bid_form(game: &Game, player_id: &str, game_id: &str, min_bid: u32) -> String {
+    let current = match game.get_proxy_bid(player_id) {
+        Some(proxy) => format!(
+            r#"<p class="text-xs opacity-70">🤖 Auto-bidding up to <strong>{} chips</strong> for you</p>"#,
+            proxy.max_amount
+        ),
+        None => String::new(),
+    };
+
+    format!(
+        r#"<form
+            hx-post="/game/{}/proxy-bid"
+            hx-swap="none"
+            class="flex gap-2 mt-2"
+        >
+            <input type="hidden" name="player_id" value="{}" />
+            <input
+                type="number"
+                name="max_amount"
+                placeholder="Max bid (auto-raise)"
+                class="input input-bordered input-sm flex-1"
+                min="{}"
+                required
+            />
+            <button type="submit" class="btn btn-sm btn-secondary">
+                Set Max
+            </button>
+        </form>
+        {}"#,
+        game_id, player_id, min_bid, current
+    )
+}
+
+/// Render the trading panel: offers to and from this player, plus a proposal form per rival
+pub fn render_trade_panel_partial(game: &Game, player_id: &str, game_id: &str) -> String {
+    if !game.config.trading_enabled {
+        return String::new();
+    }
+
+    if !game.is_trading_open() {
+        return r#"<p class="text-sm opacity-60">Trading opens between rounds.</p>"#.to_string();
+    }
+
+    let player = match game.players.get(player_id) {
+        Some(p) => p,
+        None => return r#"<div class="alert alert-error">Player not found</div>"#.to_string(),
+    };
+
+    let mut html = String::from(r#"<div class="space-y-2">"#);
+
+    for trade in &game.trade_offers {
+        if trade.to_id == player_id {
+            html.push_str(&format!(
+                r#"<div class="bg-base-200 rounded-lg p-3 text-sm space-y-2">
+                <p><strong>{}</strong> offers {} for your {}</p>
+                <div class="flex gap-2">
+                    <button class="btn btn-xs btn-success" hx-post="/game/{}/trade/{}/accept" hx-vals='{{"player_id": "{}"}}' hx-swap="none">Accept</button>
+                    <button class="btn btn-xs btn-ghost" hx-post="/game/{}/trade/{}/reject" hx-vals='{{"player_id": "{}"}}' hx-swap="none">Reject</button>
+                </div>
+            </div>"#,
+                display_player_name(game, &trade.from_id),
+                describe_trade_side(game, Some(player_id), &trade.offered_art, trade.offered_chips),
+                describe_trade_side(game, Some(player_id), &trade.requested_art, trade.requested_chips),
+                game_id,
+                trade.id,
+                player_id,
+                game_id,
+                trade.id,
+                player_id
+            ));
+        } else if trade.from_id == player_id {
+            html.push_str(&format!(
+                r#"<div class="bg-base-200 rounded-lg p-3 text-sm space-y-2">
+                <p>You offered <strong>{}</strong> {} for {}</p>
+                <button class="btn btn-xs btn-ghost" hx-post="/game/{}/trade/{}/reject" hx-vals='{{"player_id": "{}"}}' hx-swap="none">Withdraw</button>
+            </div>"#,
+                display_player_name(game, &trade.to_id),
+                describe_trade_side(game, Some(player_id), &trade.offered_art, trade.offered_chips),
+                describe_trade_side(game, Some(player_id), &trade.requested_art, trade.requested_chips),
+                game_id,
+                trade.id,
+                player_id
+            ));
+        }
+    }
+
+    for partner_id in game.turn_order.iter().filter(|id| *id != player_id) {
+        let Some(partner) = game.players.get(partner_id) else {
+            continue;
+        };
+
+        html.push_str(&format!(
+            r#"<details class="collapse collapse-arrow bg-base-200">
+            <summary class="collapse-title text-sm font-medium">Trade with {}</summary>
+            <div class="collapse-content">
+                <form hx-post="/game/{}/trade/propose" hx-swap="none" class="space-y-2">
+                    <input type="hidden" name="player_id" value="{}" />
+                    <input type="hidden" name="to_id" value="{}" />
+                    <p class="text-xs font-semibold">You give</p>
+                    {}
+                    <input type="number" name="offered_chips" min="0" max="{}" placeholder="Chips" class="input input-bordered input-sm w-full" />
+                    <p class="text-xs font-semibold">You get</p>
+                    {}
+                    <input type="number" name="requested_chips" min="0" max="{}" placeholder="Chips" class="input input-bordered input-sm w-full" />
+                    <button type="submit" class="btn btn-sm btn-primary w-full">Propose Trade</button>
+                </form>
+            </div>
+        </details>"#,
+            partner.name,
+            game_id,
+            player_id,
+            partner_id,
+            render_trade_checkboxes(game, player_id, player, "offered_art"),
+            player.chips.max(0),
+            render_trade_checkboxes(game, player_id, partner, "requested_art"),
+            partner.chips.max(0)
+        ));
+    }
+
+    html.push_str("</div>");
+    html
+}
+
+/// Announce a completed trade to everyone
+pub fn render_trade_completed_partial(game: &Game, trade: &crate::models::TradeOffer) -> String {
+    format!(
+        r#"<div class="alert alert-success text-sm mb-2">
+            <span>🤝 <strong>{}</strong> traded {} to <strong>{}</strong> for {}</span>
+        </div>"#,
+        display_player_name(game, &trade.from_id),
+        describe_trade_side(game, None, &trade.offered_art, trade.offered_chips),
+        display_player_name(game, &trade.to_id),
+        describe_trade_side(game, None, &trade.requested_art, trade.requested_chips)
+    )
+}
+
+/// One checkbox per painting in a player's collection, as the viewer sees it
+fn render_trade_checkboxes(
+    game: &Game,
+    viewer_id: &str,
+    player: &crate::models::Player,
+    name: &str,
+) -> String {
+    if player.collection.is_empty() {
+        return r#"<p class="text-xs opacity-50">No paintings</p>"#.to_string();
+    }
+
+    player
+        .collection
+        .iter()
+        .filter_map(|art| {
+            let handle = game.art_handle(art.id)?;
+            let art = game.masked_art(art, Some(viewer_id));
+            Some(format!(
+                r#"<label class="label cursor-pointer justify-start gap-2 py-0">
+                <input type="checkbox" name="{}" value="{}" class="checkbox checkbox-xs" />
+                <span class="label-text text-xs">{} ({})</span>
+            </label>"#,
+                name,
+                handle,
+                art.name,
+                art.star_display()
+            ))
+        })
+        .collect()
+}
+
+/// "Sunflowers, The Scream + 10 chips" for one side of a trade
+fn describe_trade_side(
+    game: &Game,
+    viewer_id: Option<&str>,
+    art_ids: &[usize],
+    chips: u32,
+) -> String {
+    let mut parts: Vec<String> = art_ids
+        .iter()
+        .filter_map(|id| {
+            game.players
+                .values()
+                .flat_map(|p| &p.collection)
+                .find(|a| a.id == *id)
+                .map(|a| format!("<em>{}</em>", game.masked_art(a, viewer_id).name))
+        })
+        .collect();
+    if chips > 0 {
+        parts.push(format!("{} chips", chips));
+    }
+
+    if parts.is_empty() {
+        "nothing".to_string()
+    } else {
+        parts.join(" + ")
+    }
+}
+
+/// Render a simple trigger message for game-started
+/// The actual redirect is handled by the page's hidden trigger element
+pub fn render_game_started_trigger() -> String {
+    // Just send any content - the SSE event name "game-started" is what matters
+    // The page has a hidden div with hx-trigger="sse:game-started" that will handle the redirect
+    String::from("<!-- Game starting -->")
+}
+
+/// Render a simple trigger message for game-finished
+pub fn render_game_finished_trigger() -> String {
+    // Just send any content - the SSE event name "game-finished" is what matters
     String::from("<!-- Game finished -->")
 }
 
@@ -352,131 +1495,824 @@ pub fn render_lobby_players_card(game: &Game) -> String {
     let player_count = game.players.len();
 
     format!(
-        r#"<h2 class="card-title">Players ({})</h2>
-<div class="space-y-2">
-{}
-</div>"#,
-        player_count,
-        render_player_list_partial(game)
+        r#"<h2 class="card-title">Players ({})</h2>
+<div class="space-y-2">
+{}
+</div>"#,
+        player_count,
+        render_player_list_partial(game)
+    )
+}
+
+/// Render the game settings section for host
+pub fn render_lobby_settings_host(game: &Game, game_id: &str, player_id: &str) -> String {
+    let player_count = game.players.len();
+    let can_start = player_count >= 2;
+    let button_text = if can_start {
+        "Start Game"
+    } else {
+        "Waiting for players..."
+    };
+    let disabled = if can_start { "" } else { "disabled" };
+
+    format!(
+        r#"<h2 class="card-title">Game Settings</h2>
+<form
+    hx-post="/lobby/{}/configure"
+    hx-swap="none"
+    hx-trigger="change"
+    class="space-y-4"
+>
+    {}
+
+    {}
+
+    {}
+
+    {}
+
+    {}
+
+    {}
+
+    {}
+
+    {}
+</form>
+{}
+{}
+<div class="divider"></div>
+
+<form
+    action="/lobby/{}/start?player_id={}"
+    method="post"
+>
+    <button
+        type="submit"
+        class="btn btn-primary btn-lg w-full"
+        {}
+    >
+        {}
+    </button>
+</form>"#,
+        game_id,
+        render_basic_settings(&game.config),
+        render_bidding_settings(&game.config),
+        render_economy_settings(&game.config),
+        render_round_settings(&game.config),
+        render_scoring_settings(&game.config),
+        render_deck_settings(&game.config),
+        render_hidden_info_settings(&game.config),
+        render_auction_type_settings(&game.config),
+        render_deck_filters(game, game_id),
+        render_team_assignment(game, game_id),
+        game_id,
+        player_id,
+        disabled,
+        button_text
+    )
+}
+
+/// Game mode, starting chips and round count
+fn render_basic_settings(config: &GameConfig) -> String {
+    format!(
+        r#"<div class="form-control">
+        <label class="label">
+            <span class="label-text">Game Mode</span>
+        </label>
+        <select name="game_mode" class="select select-bordered">
+            <option value="bank_auction" {}>{}</option>
+            <option value="player_auctioneer" {}>{}</option>
+        </select>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Starting Chips</span>
+            <span class="label-text-alt" id="chips-value">{}</span>
+        </label>
+        <input
+            type="range"
+            name="starting_chips"
+            min="50"
+            max="500"
+            value="{}"
+            class="range range-primary"
+            step="50"
+            oninput="document.getElementById('chips-value').textContent = this.value"
+        />
+        <div class="w-full flex justify-between text-xs px-2 opacity-50">
+            <span>50</span>
+            <span>500</span>
+        </div>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Bid Timer (seconds)</span>
+            <span class="label-text-alt" id="timer-value">{}</span>
+        </label>
+        <input
+            type="range"
+            name="bid_timer_seconds"
+            min="15"
+            max="60"
+            value="{}"
+            class="range range-secondary"
+            step="5"
+            oninput="document.getElementById('timer-value').textContent = this.value"
+        />
+        <div class="w-full flex justify-between text-xs px-2 opacity-50">
+            <span>15s</span>
+            <span>60s</span>
+        </div>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Number of Rounds</span>
+            <span class="label-text-alt" id="rounds-value">{}</span>
+        </label>
+        <input
+            type="range"
+            name="num_rounds"
+            min="1"
+            max="90"
+            value="{}"
+            class="range range-accent"
+            step="1"
+            oninput="document.getElementById('rounds-value').textContent = this.value"
+        />
+        <div class="w-full flex justify-between text-xs px-2 opacity-50">
+            <span>1</span>
+            <span>90</span>
+        </div>
+    </div>"#,
+        selected_if(config.game_mode == GameMode::BankAuction),
+        GameMode::BankAuction.name(),
+        selected_if(config.game_mode == GameMode::PlayerAuctioneer),
+        GameMode::PlayerAuctioneer.name(),
+        config.starting_chips,
+        config.starting_chips,
+        config.bid_timer_seconds,
+        config.bid_timer_seconds,
+        config.num_rounds,
+        config.num_rounds,
+    )
+}
+
+/// Minimum raise and reserve price
+fn render_bidding_settings(config: &GameConfig) -> String {
+    format!(
+        r#"<div class="form-control">
+        <label class="label">
+            <span class="label-text">Minimum Raise</span>
+        </label>
+        <div class="flex gap-2">
+            <input type="number" name="min_increment" min="1" max="100" value="{}" class="input input-bordered w-full" />
+            <select name="increment_rule" class="select select-bordered">
+                <option value="flat" {}>{}</option>
+                <option value="percent" {}>{}</option>
+            </select>
+        </div>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Reserve Price per Star</span>
+            <span class="label-text-alt">0 = no reserve</span>
+        </label>
+        <input
+            type="number"
+            name="reserve_per_star"
+            min="0"
+            max="50"
+            value="{}"
+            class="input input-bordered"
+        />
+    </div>"#,
+        config.min_increment,
+        selected_if(config.increment_rule == IncrementRule::Flat),
+        IncrementRule::Flat.name(),
+        selected_if(config.increment_rule == IncrementRule::Percent),
+        IncrementRule::Percent.name(),
+        config.reserve_per_star,
+    )
+}
+
+/// Income, trading, selling back, the market, objectives and loans
+fn render_economy_settings(config: &GameConfig) -> String {
+    format!(
+        r#"<div class="form-control">
+        <label class="label">
+            <span class="label-text">Round Income</span>
+            <span class="label-text-alt">Chip stipend / % interest on unspent chips</span>
+        </label>
+        <div class="flex gap-2">
+            <input type="number" name="round_stipend" min="0" max="100" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="interest_percent" min="0" max="50" value="{}" class="input input-bordered w-full" />
+        </div>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Trading Between Rounds</span>
+        </label>
+        <select name="trading_enabled" class="select select-bordered">
+            <option value="false" {}>Off</option>
+            <option value="true" {}>On</option>
+        </select>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Sell Back to the Bank</span>
+            <span class="label-text-alt">% of basis</span>
+        </label>
+        <div class="flex gap-2">
+            <select name="sell_back_enabled" class="select select-bordered">
+                <option value="false" {}>Off</option>
+                <option value="true" {}>On</option>
+            </select>
+            <select name="sell_back_basis" class="select select-bordered">
+                <option value="stars" {}>{}</option>
+                <option value="purchase_price" {}>{}</option>
+            </select>
+            <input type="number" name="sell_back_percent" min="10" max="100" value="{}" class="input input-bordered w-full" />
+        </div>
+        <select name="sell_back_destination" class="select select-bordered mt-2">
+            <option value="discard" {}>{}</option>
+            <option value="deck" {}>{}</option>
+        </select>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Art Market</span>
+            <span class="label-text-alt">Rounds per season</span>
+        </label>
+        <div class="flex gap-2">
+            <select name="market_enabled" class="select select-bordered">
+                <option value="false" {}>Off</option>
+                <option value="true" {}>On</option>
+            </select>
+            <input type="number" name="season_length" min="1" max="30" value="{}" class="input input-bordered w-full" />
+        </div>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Secret Objectives</span>
+        </label>
+        <select name="secret_objectives" class="select select-bordered">
+            <option value="false" {}>Off</option>
+            <option value="true" {}>On</option>
+        </select>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Bank Loans</span>
+            <span class="label-text-alt">Loan limit / % interest per round</span>
+        </label>
+        <div class="flex gap-2">
+            <select name="loans_enabled" class="select select-bordered">
+                <option value="false" {}>Off</option>
+                <option value="true" {}>On</option>
+            </select>
+            <input type="number" name="loan_limit" min="10" max="500" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="loan_interest_percent" min="0" max="50" value="{}" class="input input-bordered w-full" />
+        </div>
+    </div>"#,
+        config.round_stipend,
+        config.interest_percent,
+        selected_if(!config.trading_enabled),
+        selected_if(config.trading_enabled),
+        selected_if(!config.sell_back_enabled),
+        selected_if(config.sell_back_enabled),
+        selected_if(config.sell_back_basis == SellBackBasis::Stars),
+        SellBackBasis::Stars.name(),
+        selected_if(config.sell_back_basis == SellBackBasis::PurchasePrice),
+        SellBackBasis::PurchasePrice.name(),
+        config.sell_back_percent,
+        selected_if(config.sell_back_destination == SellBackDestination::Discard),
+        SellBackDestination::Discard.name(),
+        selected_if(config.sell_back_destination == SellBackDestination::Deck),
+        SellBackDestination::Deck.name(),
+        selected_if(!config.market_enabled),
+        selected_if(config.market_enabled),
+        config.season_length,
+        selected_if(!config.secret_objectives),
+        selected_if(config.secret_objectives),
+        selected_if(!config.loans_enabled),
+        selected_if(config.loans_enabled),
+        config.loan_limit,
+        config.loan_interest_percent,
+    )
+}
+
+/// Soft close, lot size and auction type
+fn render_round_settings(config: &GameConfig) -> String {
+    format!(
+        r#"<div class="form-control">
+        <label class="label">
+            <span class="label-text">Soft Close</span>
+            <span class="label-text-alt">Window / extension / max round (seconds)</span>
+        </label>
+        <div class="flex gap-2">
+            <input type="number" name="soft_close_window_seconds" min="0" max="60" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="soft_close_extension_seconds" min="1" max="60" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="max_round_seconds" min="15" max="600" value="{}" class="input input-bordered w-full" />
+        </div>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Paintings per Lot</span>
+            <span class="label-text-alt">Won together in one auction</span>
+        </label>
+        <input
+            type="number"
+            name="lot_size"
+            min="1"
+            max="5"
+            value="{}"
+            class="input input-bordered"
+        />
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Auction Type</span>
+        </label>
+        <select name="auction_type" class="select select-bordered">
+            {}
+        </select>
+    </div>"#,
+        config.soft_close_window_seconds,
+        config.soft_close_extension_seconds,
+        config.max_round_seconds,
+        config.lot_size,
+        render_auction_type_options(config.auction_type),
     )
 }
 
-/// Render the game settings section for host
-pub fn render_lobby_settings_host(game: &Game, game_id: &str, player_id: &str) -> String {
-    let player_count = game.players.len();
-    let can_start = player_count >= 2;
-    let button_text = if can_start {
-        "Start Game"
-    } else {
-        "Waiting for players..."
-    };
-    let disabled = if can_start { "" } else { "disabled" };
+/// Scoring rule, tie-breaker and team play
+fn render_scoring_settings(config: &GameConfig) -> String {
+    format!(
+        r#"<div class="form-control">
+        <label class="label">
+            <span class="label-text">Scoring Rule</span>
+        </label>
+        <select name="scoring" class="select select-bordered">
+            {}
+        </select>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Tie-Breaker</span>
+            <span class="label-text-alt">Leftover chips per point (0 = none)</span>
+        </label>
+        <div class="flex gap-2">
+            <select name="tie_breaker" class="select select-bordered">
+                {}
+            </select>
+            <input type="number" name="chips_per_point" min="0" max="100" value="{}" class="input input-bordered w-full" />
+        </div>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Team Play</span>
+            <span class="label-text-alt">Team score</span>
+        </label>
+        <div class="flex gap-2">
+            <select name="teams_enabled" class="select select-bordered">
+                <option value="false" {}>Off</option>
+                <option value="true" {}>On</option>
+            </select>
+            <select name="team_scoring" class="select select-bordered w-full">
+                <option value="sum_scores" {}>{}</option>
+                <option value="merge_collections" {}>{}</option>
+            </select>
+        </div>
+    </div>"#,
+        render_scoring_options(config.scoring),
+        render_tie_breaker_options(config.tie_breaker),
+        config.chips_per_point,
+        selected_if(!config.teams_enabled),
+        selected_if(config.teams_enabled),
+        selected_if(config.team_scoring == TeamScoring::SumScores),
+        TeamScoring::SumScores.name(),
+        selected_if(config.team_scoring == TeamScoring::MergeCollections),
+        TeamScoring::MergeCollections.name(),
+    )
+}
 
+/// Deck strategy, preview and shuffle seed
+fn render_deck_settings(config: &GameConfig) -> String {
     format!(
-        r#"<h2 class="card-title">Game Settings</h2>
-<form
-    hx-post="/lobby/{}/configure"
-    hx-swap="none"
-    hx-trigger="change"
-    class="space-y-4"
->
+        r#"<div class="form-control">
+        <label class="label">
+            <span class="label-text">Deck</span>
+            <span class="label-text-alt">Pieces per focus / 1⭐ 2⭐ 3⭐ weights</span>
+        </label>
+        <select name="deck_strategy" class="select select-bordered">
+            {}
+        </select>
+        <div class="flex gap-2 mt-2">
+            <input type="number" name="pieces_per_focus" min="1" max="9" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="one_star_weight" min="0" max="10" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="two_star_weight" min="0" max="10" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="three_star_weight" min="0" max="10" value="{}" class="input input-bordered w-full" />
+        </div>
+    </div>
+
     <div class="form-control">
         <label class="label">
-            <span class="label-text">Starting Chips</span>
-            <span class="label-text-alt" id="chips-value">{}</span>
+            <span class="label-text">Coming Up Preview</span>
+            <span class="label-text-alt">Upcoming paintings shown (0 = hidden)</span>
         </label>
         <input
-            type="range"
-            name="starting_chips"
-            min="50"
-            max="500"
+            type="number"
+            name="preview_count"
+            min="0"
+            max="10"
             value="{}"
-            class="range range-primary"
-            step="50"
-            oninput="document.getElementById('chips-value').textContent = this.value"
+            class="input input-bordered"
         />
-        <div class="w-full flex justify-between text-xs px-2 opacity-50">
-            <span>50</span>
-            <span>500</span>
-        </div>
     </div>
 
     <div class="form-control">
         <label class="label">
-            <span class="label-text">Bid Timer (seconds)</span>
-            <span class="label-text-alt" id="timer-value">{}</span>
+            <span class="label-text">Shuffle Seed</span>
+            <span class="label-text-alt">Same seed, same deck</span>
         </label>
         <input
-            type="range"
-            name="bid_timer_seconds"
-            min="15"
-            max="60"
+            type="text"
+            inputmode="numeric"
+            name="seed"
             value="{}"
-            class="range range-secondary"
-            step="5"
-            oninput="document.getElementById('timer-value').textContent = this.value"
+            placeholder="Random"
+            class="input input-bordered"
         />
-        <div class="w-full flex justify-between text-xs px-2 opacity-50">
-            <span>15s</span>
-            <span>60s</span>
+    </div>"#,
+        render_deck_strategy_options(config.deck_strategy),
+        config.pieces_per_focus,
+        config.star_weights[0],
+        config.star_weights[1],
+        config.star_weights[2],
+        config.preview_count,
+        config.seed.map(|seed| seed.to_string()).unwrap_or_default(),
+    )
+}
+
+/// Blind lots, forgeries and authentication
+fn render_hidden_info_settings(config: &GameConfig) -> String {
+    format!(
+        r#"<div class="form-control">
+        <label class="label">
+            <span class="label-text">Blind Lots</span>
+            <span class="label-text-alt">% of paintings / hidden / revealed to</span>
+        </label>
+        <div class="flex gap-2">
+            <input type="number" name="blind_lot_percent" min="0" max="100" value="{}" class="input input-bordered w-full" />
+            <select name="blind_hides" class="select select-bordered">
+                <option value="one_attribute" {}>{}</option>
+                <option value="everything" {}>{}</option>
+            </select>
+            <select name="blind_reveal" class="select select-bordered">
+                <option value="everyone" {}>{}</option>
+                <option value="winner" {}>{}</option>
+            </select>
         </div>
     </div>
 
     <div class="form-control">
         <label class="label">
-            <span class="label-text">Number of Rounds</span>
-            <span class="label-text-alt" id="rounds-value">{}</span>
+            <span class="label-text">Forgeries</span>
+            <span class="label-text-alt">% of paintings / points lost per fake</span>
+        </label>
+        <div class="flex gap-2">
+            <input type="number" name="forgery_percent" min="0" max="50" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="forgery_penalty" min="0" max="20" value="{}" class="input input-bordered w-full" />
+        </div>
+        <label class="label">
+            <span class="label-text">Authentication</span>
+            <span class="label-text-alt">Chips to check one painting</span>
+        </label>
+        <div class="flex gap-2">
+            <select name="authentication_enabled" class="select select-bordered">
+                <option value="false" {}>Off</option>
+                <option value="true" {}>On</option>
+            </select>
+            <input type="number" name="authentication_cost" min="1" max="100" value="{}" class="input input-bordered w-full" />
+        </div>
+    </div>"#,
+        config.blind_lot_percent,
+        selected_if(config.blind_hides == BlindHides::OneAttribute),
+        BlindHides::OneAttribute.name(),
+        selected_if(config.blind_hides == BlindHides::Everything),
+        BlindHides::Everything.name(),
+        selected_if(config.blind_reveal == BlindReveal::Everyone),
+        BlindReveal::Everyone.name(),
+        selected_if(config.blind_reveal == BlindReveal::Winner),
+        BlindReveal::Winner.name(),
+        config.forgery_percent,
+        config.forgery_penalty,
+        selected_if(!config.authentication_enabled),
+        selected_if(config.authentication_enabled),
+        config.authentication_cost,
+    )
+}
+
+/// Settings that only apply to some auction types
+fn render_auction_type_settings(config: &GameConfig) -> String {
+    format!(
+        r#"<div class="form-control">
+        <label class="label">
+            <span class="label-text">Second-Price Minimum</span>
+            <span class="label-text-alt">Paid by a lone bidder</span>
         </label>
         <input
-            type="range"
-            name="num_rounds"
+            type="number"
+            name="second_price_minimum"
             min="1"
-            max="90"
+            max="100"
             value="{}"
-            class="range range-accent"
-            step="1"
-            oninput="document.getElementById('rounds-value').textContent = this.value"
+            class="input input-bordered"
         />
-        <div class="w-full flex justify-between text-xs px-2 opacity-50">
-            <span>1</span>
-            <span>90</span>
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Once-Around Turn Clock (seconds)</span>
+        </label>
+        <input
+            type="number"
+            name="turn_timer_seconds"
+            min="5"
+            max="60"
+            value="{}"
+            class="input input-bordered"
+        />
+    </div>
+
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Dutch Auction Prices</span>
+            <span class="label-text-alt">Start / drop per second / floor</span>
+        </label>
+        <div class="flex gap-2">
+            <input type="number" name="dutch_start_price" min="1" max="500" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="dutch_price_step" min="1" max="50" value="{}" class="input input-bordered w-full" />
+            <input type="number" name="dutch_floor_price" min="1" max="500" value="{}" class="input input-bordered w-full" />
         </div>
     </div>
-</form>
 
-<div class="divider"></div>
+    <div class="form-control">
+        <label class="label">
+            <span class="label-text">Unsold at Floor Price</span>
+        </label>
+        <select name="dutch_floor_rule" class="select select-bordered">
+            <option value="discard" {}>{}</option>
+            <option value="reserve" {}>{}</option>
+        </select>
+    </div>"#,
+        config.second_price_minimum,
+        config.turn_timer_seconds,
+        config.dutch_start_price,
+        config.dutch_price_step,
+        config.dutch_floor_price,
+        selected_if(config.dutch_floor_rule == FloorRule::Discard),
+        FloorRule::Discard.name(),
+        selected_if(config.dutch_floor_rule == FloorRule::Reserve),
+        FloorRule::Reserve.name(),
+    )
+}
 
-<form
-    action="/lobby/{}/start?player_id={}"
-    method="post"
->
-    <button
-        type="submit"
-        class="btn btn-primary btn-lg w-full"
-        {}
-    >
-        {}
-    </button>
-</form>"#,
-        game_id,
-        game.config.starting_chips,
-        game.config.starting_chips,
-        game.config.bid_timer_seconds,
-        game.config.bid_timer_seconds,
-        game.config.num_rounds,
-        game.config.num_rounds,
-        game_id,
-        player_id,
-        disabled,
-        button_text
+/// Movement and artist checkboxes that shape the deck
+fn render_deck_filters(game: &Game, game_id: &str) -> String {
+    let checkbox = |name: &str, value: String, label: &str, checked: bool| {
+        format!(
+            r#"<label class="label cursor-pointer justify-start gap-2 py-0.5">
+            <input type="checkbox" name="{}" value="{}" class="checkbox checkbox-xs" {} />
+            <span class="label-text text-xs">{}</span>
+        </label>"#,
+            name,
+            value,
+            if checked { "checked" } else { "" },
+            label
+        )
+    };
+
+    let movements = all_movements();
+    let deck_movements: String = movements
+        .iter()
+        .map(|m| {
+            checkbox(
+                "deck_movement",
+                format!("{:?}", m),
+                m.name(),
+                game.config.deck_movements.contains(m),
+            )
+        })
+        .collect();
+    let focus_movements: String = movements
+        .iter()
+        .map(|m| {
+            checkbox(
+                "focus_movement",
+                format!("{:?}", m),
+                m.name(),
+                game.config.focus_movements.contains(m),
+            )
+        })
+        .collect();
+    let focus_artists: String = all_artists()
+        .iter()
+        .map(|a| {
+            checkbox(
+                "focus_artist",
+                format!("{:?}", a),
+                a.name(),
+                game.config.focus_artists.contains(a),
+            )
+        })
+        .collect();
+
+    format!(
+        r#"
+<details class="collapse collapse-arrow bg-base-200 rounded-lg mt-4">
+    <summary class="collapse-title font-semibold">Deck Composition</summary>
+    <form hx-post="/lobby/{}/deck" hx-swap="none" hx-trigger="change" class="collapse-content space-y-3">
+        <div>
+            <p class="text-sm font-semibold">Only deal these movements <span class="opacity-50">(none = all)</span></p>
+            <div class="grid grid-cols-2">{}</div>
+        </div>
+        <div>
+            <p class="text-sm font-semibold">Focus movements <span class="opacity-50">(Guaranteed focus pieces)</span></p>
+            <div class="grid grid-cols-2">{}</div>
+        </div>
+        <div>
+            <p class="text-sm font-semibold">Focus artists <span class="opacity-50">(Guaranteed focus pieces)</span></p>
+            <div class="grid grid-cols-2">{}</div>
+        </div>
+    </form>
+</details>
+"#,
+        game_id, deck_movements, focus_movements, focus_artists
+    )
+}
+
+/// Render the <option> list for the deck strategy select
+fn render_deck_strategy_options(selected: DeckStrategy) -> String {
+    [
+        ("random", DeckStrategy::Random),
+        ("guaranteed", DeckStrategy::Guaranteed),
+        ("star_target", DeckStrategy::StarTarget),
+        ("complete_sets", DeckStrategy::CompleteSets),
+    ]
+    .iter()
+    .map(|(value, strategy)| {
+        format!(
+            r#"<option value="{}" {}>{}</option>"#,
+            value,
+            selected_if(*strategy == selected),
+            strategy.name()
+        )
+    })
+    .collect()
+}
+
+/// One team picker per player, shown to the host while team play is on
+fn render_team_assignment(game: &Game, game_id: &str) -> String {
+    if !game.config.teams_enabled {
+        return String::new();
+    }
+
+    let max_team = (game.players.len() / 2).max(2) as u8;
+    let mut players: Vec<&crate::models::Player> = game.players.values().collect();
+    players.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let rows: String = players
+        .iter()
+        .map(|player| {
+            let options: String = (0..=max_team.max(player.team.unwrap_or(0)))
+                .map(|team| {
+                    format!(
+                        r#"<option value="{}" {}>{}</option>"#,
+                        team,
+                        selected_if(player.team.unwrap_or(0) == team),
+                        if team == 0 {
+                            "No team".to_string()
+                        } else {
+                            format!("Team {}", team)
+                        }
+                    )
+                })
+                .collect();
+            format!(
+                r#"<form hx-post="/lobby/{}/team" hx-swap="none" hx-trigger="change" class="flex items-center gap-2">
+        <input type="hidden" name="player_id" value="{}" />
+        <span class="flex-1">{}</span>
+        <select name="team" class="select select-bordered select-sm">{}</select>
+    </form>"#,
+                game_id, player.id, player.name, options
+            )
+        })
+        .collect();
+
+    format!(
+        r#"
+<div class="space-y-2 mt-4">
+    <h3 class="font-semibold">Teams</h3>
+    {}
+</div>
+"#,
+        rows
     )
 }
 
+/// Render the <option> list for the auction type select
+fn render_auction_type_options(selected: AuctionType) -> String {
+    [
+        ("open", AuctionType::Open),
+        ("sealed_first_price", AuctionType::SealedFirstPrice),
+        ("sealed_second_price", AuctionType::SealedSecondPrice),
+        ("dutch", AuctionType::Dutch),
+        ("once_around", AuctionType::OnceAround),
+    ]
+    .iter()
+    .map(|(value, auction_type)| {
+        format!(
+            r#"<option value="{}" {}>{}</option>"#,
+            value,
+            selected_if(*auction_type == selected),
+            auction_type.name()
+        )
+    })
+    .collect()
+}
+
+/// Render the <option> list for the scoring rule select
+fn render_scoring_options(selected: ScoringMode) -> String {
+    [
+        ("max_bonus", ScoringMode::MaxBonus),
+        ("sum_bonus", ScoringMode::SumBonus),
+        ("stars_only", ScoringMode::StarsOnly),
+        ("set_completion", ScoringMode::SetCompletion),
+        ("diversity", ScoringMode::Diversity),
+    ]
+    .iter()
+    .map(|(value, scoring)| {
+        format!(
+            r#"<option value="{}" {} title="{}">{}</option>"#,
+            value,
+            selected_if(*scoring == selected),
+            scoring.rule().description(),
+            scoring.rule().name()
+        )
+    })
+    .collect()
+}
+
+/// Render the <option> list for the tie-breaker select
+fn render_tie_breaker_options(selected: TieBreaker) -> String {
+    [
+        ("shared", TieBreaker::Shared),
+        ("most_chips", TieBreaker::MostChips),
+        ("fewest_pieces", TieBreaker::FewestPieces),
+        ("highest_star", TieBreaker::HighestStar),
+    ]
+    .iter()
+    .map(|(value, tie_breaker)| {
+        format!(
+            r#"<option value="{}" {}>{}</option>"#,
+            value,
+            selected_if(*tie_breaker == selected),
+            tie_breaker.name()
+        )
+    })
+    .collect()
+}
+
+/// The `selected` attribute for an <option>, when it applies
+fn selected_if(selected: bool) -> &'static str {
+    if selected { "selected" } else { "" }
+}
+
 /// Render the game settings section for non-host players
 pub fn render_lobby_settings_player(game: &Game) -> String {
     format!(
         r#"<h2 class="card-title">Game Settings</h2>
 <div class="space-y-4">
+    <div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">Game Mode</div>
+        <div class="stat-value text-lg">{}</div>
+    </div>
+
     <div class="stat bg-base-200 rounded-lg">
         <div class="stat-title">Starting Chips</div>
         <div class="stat-value text-primary">{}</div>
@@ -492,6 +2328,55 @@ pub fn render_lobby_settings_player(game: &Game) -> String {
         <div class="stat-value text-accent">{}</div>
     </div>
 
+    <div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">Paintings per Lot</div>
+        <div class="stat-value text-lg">{}</div>
+    </div>
+
+    <div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">Auction Type</div>
+        <div class="stat-value text-lg">{}</div>
+    </div>
+
+    <div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">Scoring Rule</div>
+        <div class="stat-value text-lg">{}</div>
+        <div class="stat-desc whitespace-normal">{}</div>
+    </div>
+
+    <div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">Tie-Breaker</div>
+        <div class="stat-value text-lg">{}</div>
+        <div class="stat-desc">{}</div>
+    </div>
+
+    <div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">Team Play</div>
+        <div class="stat-value text-lg">{}</div>
+    </div>
+
+    <div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">Deck</div>
+        <div class="stat-value text-lg">{}</div>
+        <div class="stat-desc whitespace-normal">{}</div>
+        <div class="stat-desc">{}</div>
+        <div class="stat-desc">{}</div>
+    </div>
+
+    <div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">Blind Lots</div>
+        <div class="stat-value text-lg">{}</div>
+        <div class="stat-desc">{}</div>
+    </div>
+
+    <div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">Forgeries</div>
+        <div class="stat-value text-lg">{}</div>
+        <div class="stat-desc">{}</div>
+    </div>
+
+    {}
+
     <div class="alert">
         <svg xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24" class="stroke-info shrink-0 w-6 h-6">
             <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13 16h-1v-4h-1m1-4h.01M21 12a9 9 0 11-18 0 9 9 0 0118 0z"></path>
@@ -499,6 +2384,154 @@ pub fn render_lobby_settings_player(game: &Game) -> String {
         <span>Waiting for host to start the game...</span>
     </div>
 </div>"#,
-        game.config.starting_chips, game.config.bid_timer_seconds, game.config.num_rounds
+        game.config.game_mode.name(),
+        game.config.starting_chips,
+        game.config.bid_timer_seconds,
+        game.config.num_rounds,
+        game.config.lot_size,
+        game.config.auction_type.name(),
+        game.config.scoring.rule().name(),
+        game.config.scoring.rule().description(),
+        game.config.tie_breaker.name(),
+        if game.config.chips_per_point > 0 {
+            format!(
+                "Leftover chips score 1 point per {}",
+                game.config.chips_per_point
+            )
+        } else {
+            "Leftover chips don't score".to_string()
+        },
+        if game.config.teams_enabled {
+            game.config.team_scoring.name()
+        } else {
+            "Off"
+        },
+        game.config.deck_strategy.name(),
+        if game.config.deck_movements.is_empty() {
+            "All movements".to_string()
+        } else {
+            game.config
+                .deck_movements
+                .iter()
+                .map(|m| m.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        },
+        match game.config.seed {
+            Some(seed) => format!("🎲 Seed {}", seed),
+            None => "🎲 Random shuffle".to_string(),
+        },
+        match game.config.preview_count {
+            0 => "🔭 Upcoming paintings hidden".to_string(),
+            count => format!("🔭 Next {} paintings shown", count),
+        },
+        match game.config.blind_lot_percent {
+            0 => "Off".to_string(),
+            percent => format!("{}% of paintings", percent),
+        },
+        if game.config.blind_lot_percent > 0 {
+            format!(
+                "🙈 {} hidden, revealed to {}",
+                game.config.blind_hides.name(),
+                game.config.blind_reveal.name().to_lowercase()
+            )
+        } else {
+            "Every painting is fully known".to_string()
+        },
+        match (game.config.forgery_percent, game.config.forgery_penalty) {
+            (0, _) => "Off".to_string(),
+            (percent, 0) => format!("{}% of paintings", percent),
+            (percent, penalty) => format!("{}% of paintings, -{} each", percent, penalty),
+        },
+        if game.config.authentication_enabled {
+            format!(
+                "🔍 Authenticate a painting for {} chips",
+                game.config.authentication_cost
+            )
+        } else {
+            "🔍 No authentication".to_string()
+        },
+        render_rule_stats(&game.config)
     )
 }
+
+/// Loans, trading, the market, objectives, Dutch prices and soft close, for non-host players
+fn render_rule_stats(config: &GameConfig) -> String {
+    let stat = |title: &str, value: String, desc: String| {
+        format!(
+            r#"<div class="stat bg-base-200 rounded-lg">
+        <div class="stat-title">{}</div>
+        <div class="stat-value text-lg">{}</div>
+        <div class="stat-desc whitespace-normal">{}</div>
+    </div>"#,
+            title, value, desc
+        )
+    };
+    let on_off = |enabled: bool| if enabled { "On" } else { "Off" }.to_string();
+
+    [
+        if config.loans_enabled {
+            stat(
+                "Bank Loans",
+                format!("Up to {} chips", config.loan_limit),
+                format!("🏦 {}% interest per round", config.loan_interest_percent),
+            )
+        } else {
+            stat("Bank Loans", on_off(false), "🏦 No borrowing".to_string())
+        },
+        stat(
+            "Trading",
+            on_off(config.trading_enabled),
+            "🤝 Swap paintings and chips between rounds".to_string(),
+        ),
+        if config.market_enabled {
+            stat(
+                "Art Market",
+                format!("{}-round seasons", config.season_length),
+                "📈 Best-selling artists gain value each season".to_string(),
+            )
+        } else {
+            stat(
+                "Art Market",
+                on_off(false),
+                "📈 Artists keep their value".to_string(),
+            )
+        },
+        stat(
+            "Secret Objectives",
+            on_off(config.secret_objectives),
+            "🎯 A hidden bonus goal for each player".to_string(),
+        ),
+        stat(
+            "Dutch Auction Prices",
+            format!(
+                "{} → {} chips",
+                config.dutch_start_price, config.dutch_floor_price
+            ),
+            format!(
+                "Drops {} per second · unsold at the floor: {}",
+                config.dutch_price_step,
+                config.dutch_floor_rule.name().to_lowercase()
+            ),
+        ),
+        match config.soft_close_window_seconds {
+            0 => stat(
+                "Soft Close",
+                on_off(false),
+                format!(
+                    "⏱️ Rounds end on time, {}s at most",
+                    config.max_round_seconds
+                ),
+            ),
+            window => stat(
+                "Soft Close",
+                format!("+{}s", config.soft_close_extension_seconds),
+                format!(
+                    "⏱️ Bids in the last {}s extend the clock, up to {}s a round",
+                    window, config.max_round_seconds
+                ),
+            ),
+        },
+    ]
+    .join("\n\n    ")
+}
@@ -14,14 +14,46 @@ pub struct ResultsTemplate {
     player_id: String,
     is_winner: bool,
     final_scores: Vec<PlayerScore>,
+    team_scores: Vec<TeamScore>, // Empty unless playing in teams
     next_game_id: Option<String>,
+    tie_break: Option<String>,
+    seed: Option<u64>, // Enter in the lobby to replay the same deck
+    scoring_name: &'static str,
+    scoring_description: &'static str,
+    forgeries_enabled: bool,
+    forgeries: Vec<ExposedForgery>,
 }
 
 pub struct PlayerScore {
     pub name: String,
     pub score: u32,
     pub is_winner: bool,
+    pub team: Option<u8>,
     pub collection_count: usize,
+    pub chip_points: u32,  // Points from leftover chips
+    pub market_bonus: u32, // Market value of the collection
+    pub debt_penalty: u32, // Points lost to unpaid debt
+    pub objective: String, // Revealed secret objective, empty if none was dealt
+    pub objective_met: bool,
+    pub objective_bonus: u32,
+    pub breakdown_html: String, // Itemized collection score
+    pub forgery_count: usize,
+    pub forgery_penalty: u32,
+}
+
+pub struct ExposedForgery {
+    pub name: &'static str,
+    pub artist: &'static str,
+    pub stars: u8,
+    pub owner: String,
+    pub was_known: bool, // The owner had it authenticated
+}
+
+pub struct TeamScore {
+    pub team: u8,
+    pub members: String,
+    pub score: u32,
+    pub is_winner: bool,
 }
 
 #[derive(Deserialize)]
@@ -41,15 +73,17 @@ pub async fn show_results(
     };
 
     // Check if game is finished
-    let (winner_ids, scores, next_game_id) = match &game.state {
+    let (winner_ids, scores, next_game_id, tie_break) = match &game.state {
         GameState::Finished {
             winner_ids,
             final_scores,
             next_game_id,
+            tie_break,
         } => (
             winner_ids.clone(),
             final_scores.clone(),
             next_game_id.clone(),
+            tie_break.clone(),
         ),
         _ => {
             return Err(Html("Game not finished yet".to_string()));
@@ -66,17 +100,76 @@ pub async fn show_results(
                 name: player.name.clone(),
                 score: *score,
                 is_winner: winner_ids.contains(pid),
+                team: player.team.filter(|_| game.config.teams_enabled),
                 collection_count: player.collection.len(),
+                chip_points: game.chip_points(player),
+                market_bonus: game.market_bonus(player),
+                debt_penalty: player.debt_penalty(),
+                objective: player
+                    .objective
+                    .map(|o| o.description.to_string())
+                    .unwrap_or_default(),
+                objective_met: player.objective.is_some_and(|o| o.is_met(player)),
+                objective_bonus: player.objective.map(|o| o.bonus).unwrap_or(0),
+                breakdown_html: crate::handlers::partials::render_score_breakdown(
+                    &player.score_breakdown(game.config.scoring),
+                ),
+                forgery_count: player.forgeries.len(),
+                forgery_penalty: game.forgery_penalty(player),
             }
         })
         .collect();
 
+    let mut team_scores: Vec<TeamScore> = if game.config.teams_enabled {
+        game.teams()
+            .into_iter()
+            .map(|team| {
+                let members = game.team_members(team);
+                TeamScore {
+                    team,
+                    members: members
+                        .iter()
+                        .map(|p| p.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    score: game.team_score(team),
+                    is_winner: members.iter().any(|p| winner_ids.contains(&p.id)),
+                }
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    team_scores.sort_by_key(|t| (std::cmp::Reverse(t.score), !t.is_winner));
+
+    let mut forgeries: Vec<ExposedForgery> = game
+        .players
+        .values()
+        .flat_map(|player| {
+            player.forgeries.iter().map(|art| ExposedForgery {
+                name: art.name,
+                artist: art.artist.name(),
+                stars: art.stars,
+                owner: player.name.clone(),
+                was_known: player.authenticated.contains(&art.id),
+            })
+        })
+        .collect();
+    forgeries.sort_by(|a, b| a.owner.cmp(&b.owner).then(a.name.cmp(b.name)));
+
     Ok(ResultsTemplate {
         game_id,
         player_id,
         is_winner,
         final_scores,
+        team_scores,
         next_game_id,
+        tie_break,
+        seed: game.seed,
+        scoring_name: game.config.scoring.rule().name(),
+        scoring_description: game.config.scoring.rule().description(),
+        forgeries_enabled: game.config.forgery_percent > 0,
+        forgeries,
     })
 }
 
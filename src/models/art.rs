@@ -112,3 +112,55 @@ pub struct ArtPiece {
     pub movement: Movement,
     pub stars: u8, // 1-3
 }
+
+/// Which attributes of a blind lot's painting are kept from bidders
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct HiddenAttributes {
+    pub artist: bool,
+    pub movement: bool,
+    pub stars: bool,
+}
+
+/// What a player can see of a painting, with a blind lot's hidden attributes removed
+#[derive(Debug, Clone, Serialize)]
+pub struct MaskedArt {
+    pub name: &'static str, // A blind painting's title would give its artist away
+    pub artist: Option<Artist>,
+    pub movement: Option<Movement>,
+    pub stars: Option<u8>,
+    pub is_blind: bool,
+}
+
+impl ArtPiece {
+    pub fn masked(&self, hidden: Option<HiddenAttributes>) -> MaskedArt {
+        let hidden = hidden.unwrap_or_default();
+        let is_blind = hidden != HiddenAttributes::default();
+        MaskedArt {
+            name: if is_blind { "Untitled" } else { self.name },
+            artist: (!hidden.artist).then_some(self.artist),
+            movement: (!hidden.movement).then_some(self.movement),
+            stars: (!hidden.stars).then_some(self.stars),
+            is_blind,
+        }
+    }
+}
+
+impl MaskedArt {
+    pub fn artist_name(&self) -> &'static str {
+        self.artist.map(|a| a.name()).unwrap_or("Unknown artist")
+    }
+
+    pub fn movement_name(&self) -> &'static str {
+        self.movement
+            .map(|m| m.name())
+            .unwrap_or("Unknown movement")
+    }
+
+    /// Star emoji, or a question mark when the value is hidden
+    pub fn star_display(&self) -> String {
+        match self.stars {
+            Some(stars) => (0..stars).map(|_| "⭐").collect(),
+            None => "❓".to_string(),
+        }
+    }
+}
@@ -7,3 +7,11 @@ pub struct Bid {
     pub amount: u32,
     pub timestamp: DateTime<Utc>,
 }
+
+/// Hidden ceiling the engine bids up to on a player's behalf
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyBid {
+    pub player_id: String,
+    pub max_amount: u32,
+    pub timestamp: DateTime<Utc>,
+}
@@ -1,11 +1,19 @@
-use super::art::ArtPiece;
-use super::bid::Bid;
+use super::art::{ArtPiece, Artist, HiddenAttributes, MaskedArt, Movement};
+use super::bid::{Bid, ProxyBid};
 use super::player::Player;
+use super::scoring::ScoringMode;
+use super::trade::TradeOffer;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use tokio::task::JoinHandle;
 
+/// Chips a single star is worth when the bank values a painting
+pub const STAR_VALUE_CHIPS: u32 = 10;
+
+/// Market value gained per piece by the season's three best-selling artists
+pub const MARKET_AWARDS: [u32; 3] = [5, 3, 1];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GameState {
     Lobby,
@@ -17,26 +25,419 @@ pub enum GameState {
         winner_ids: Vec<String>,          // Support ties
         final_scores: Vec<(String, u32)>, // (player_id, score)
         next_game_id: Option<String>,     // Pre-created game for "Play Again"
+        tie_break: Option<String>,        // How a tie for first was settled, if there was one
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuctionType {
+    /// Open ascending auction: every bid is public and must beat the current high bid
+    #[default]
+    Open,
+    /// Sealed first-price auction: one hidden bid per player, revealed when the timer expires
+    SealedFirstPrice,
+    /// Sealed second-price (Vickrey) auction: the highest bidder wins but pays the runner-up's bid
+    SealedSecondPrice,
+    /// Dutch auction: the price falls every second until someone buys
+    Dutch,
+    /// Once-around auction: each player gets exactly one turn to raise or pass, in order
+    OnceAround,
+}
+
+impl AuctionType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AuctionType::Open => "Open Auction",
+            AuctionType::SealedFirstPrice => "Sealed Bid",
+            AuctionType::SealedSecondPrice => "Sealed Second-Price",
+            AuctionType::Dutch => "Dutch (Descending)",
+            AuctionType::OnceAround => "Once Around",
+        }
+    }
+
+    pub fn is_sealed(&self) -> bool {
+        matches!(
+            self,
+            AuctionType::SealedFirstPrice | AuctionType::SealedSecondPrice
+        )
+    }
+}
+
+/// Who puts paintings up for auction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameMode {
+    /// The bank deals each painting and winning bids leave the economy
+    #[default]
+    BankAuction,
+    /// Paintings are dealt into hands; sellers take turns auctioning them and keep the proceeds
+    PlayerAuctioneer,
+}
+
+impl GameMode {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameMode::BankAuction => "Bank Auction",
+            GameMode::PlayerAuctioneer => "Player Auctioneers",
+        }
+    }
+}
+
+/// What happens to a Dutch lot that reaches the floor price unsold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FloorRule {
+    #[default]
+    Discard,
+    /// Set aside and auctioned once more after the deck runs out
+    Reserve,
+}
+
+impl FloorRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FloorRule::Discard => "Discard",
+            FloorRule::Reserve => "Re-auction at end",
+        }
+    }
+}
+
+/// How the minimum raise over the current high bid is worked out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IncrementRule {
+    /// A fixed number of chips
+    #[default]
+    Flat,
+    /// A percentage of the current high bid, rounded up
+    Percent,
+}
+
+impl IncrementRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            IncrementRule::Flat => "Chips",
+            IncrementRule::Percent => "Percent",
+        }
+    }
+}
+
+/// What the bank's buy-back price is a fraction of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SellBackBasis {
+    /// The piece's star value
+    #[default]
+    Stars,
+    /// What the player paid for it (their share of the lot price)
+    PurchasePrice,
+}
+
+impl SellBackBasis {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SellBackBasis::Stars => "Star value",
+            SellBackBasis::PurchasePrice => "Purchase price",
+        }
+    }
+}
+
+/// Where paintings sold back to the bank end up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SellBackDestination {
+    #[default]
+    Discard,
+    /// The bottom of the deck, to be auctioned again
+    Deck,
+}
+
+impl SellBackDestination {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SellBackDestination::Discard => "Discard",
+            SellBackDestination::Deck => "Back into the deck",
+        }
+    }
+}
+
+/// How the deck is put together at the start of the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeckStrategy {
+    /// A uniform random selection of paintings
+    #[default]
+    Random,
+    /// A set number of pieces from each focus artist and movement, the rest random
+    Guaranteed,
+    /// Stars dealt in proportion to the configured weights
+    StarTarget,
+    /// Only whole artist sets, so every painting dealt can complete a set
+    CompleteSets,
+}
+
+impl DeckStrategy {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeckStrategy::Random => "Random",
+            DeckStrategy::Guaranteed => "Guaranteed focus pieces",
+            DeckStrategy::StarTarget => "Star mix",
+            DeckStrategy::CompleteSets => "Complete sets only",
+        }
+    }
+}
+
+/// What a blind lot keeps from bidders
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlindHides {
+    /// One of artist, movement or stars, picked at random per painting
+    #[default]
+    OneAttribute,
+    Everything,
+}
+
+impl BlindHides {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BlindHides::OneAttribute => "One attribute",
+            BlindHides::Everything => "Everything",
+        }
+    }
+}
+
+/// Who learns what a blind lot was once its round closes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlindReveal {
+    #[default]
+    Everyone,
+    /// Only whoever ends up owning it
+    Winner,
+}
+
+impl BlindReveal {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BlindReveal::Everyone => "Everyone",
+            BlindReveal::Winner => "Winner only",
+        }
+    }
+}
+
+/// Star value assumed for a reserve price when a blind lot hides the real one
+pub const BLIND_STARS: u32 = 2;
+
+/// How a team's members add up to one score
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamScoring {
+    /// Each member scores their own collection and the team adds them up
+    #[default]
+    SumScores,
+    /// Members' collections are scored together as one, so sets can span teammates
+    MergeCollections,
+}
+
+impl TeamScoring {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TeamScoring::SumScores => "Sum member scores",
+            TeamScoring::MergeCollections => "Merge collections",
+        }
+    }
+}
+
+/// How a tie for the highest score is settled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreaker {
+    /// Everyone tied on the top score wins
+    #[default]
+    Shared,
+    MostChips,
+    FewestPieces,
+    /// Owner of the single piece with the most stars
+    HighestStar,
+}
+
+impl TieBreaker {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TieBreaker::Shared => "Shared win",
+            TieBreaker::MostChips => "Most leftover chips",
+            TieBreaker::FewestPieces => "Fewest pieces",
+            TieBreaker::HighestStar => "Highest-star piece",
+        }
+    }
+
+    /// Ranking key for a tied player or team, where higher is better
+    pub fn key(&self, players: &[&Player]) -> i64 {
+        match self {
+            TieBreaker::Shared => 0,
+            TieBreaker::MostChips => players.iter().map(|p| p.chips as i64 - p.debt as i64).sum(),
+            TieBreaker::FewestPieces => {
+                -(players
+                    .iter()
+                    .map(|p| p.collection.len() as i64)
+                    .sum::<i64>())
+            }
+            TieBreaker::HighestStar => players
+                .iter()
+                .flat_map(|p| &p.collection)
+                .map(|a| a.stars as i64)
+                .max()
+                .unwrap_or(0),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameConfig {
+    pub game_mode: GameMode,
     pub starting_chips: u32,
     pub bid_timer_seconds: u64,
     pub num_rounds: usize,
+    pub auction_type: AuctionType,
+    pub second_price_minimum: u32, // Price paid in second-price rounds with a single bidder
+    pub lot_size: usize,           // Paintings auctioned together each round
+    pub dutch_start_price: u32,
+    pub dutch_price_step: u32, // Price drop per second
+    pub dutch_floor_price: u32,
+    pub dutch_floor_rule: FloorRule,
+    pub turn_timer_seconds: u64, // Clock for each turn of a once-around round
+    pub soft_close_window_seconds: u64, // Bids this close to the end extend the clock
+    pub soft_close_extension_seconds: u64,
+    pub max_round_seconds: u64, // Hard limit on an open round, extensions included
+    pub min_increment: u32,     // Minimum raise, in chips or percent depending on the rule
+    pub increment_rule: IncrementRule,
+    pub reserve_per_star: u32, // Reserve price of a lot per star it carries (0 = no reserve)
+    pub round_stipend: u32,    // Chips every player receives at the start of each new round
+    pub interest_percent: u32, // Interest paid on unspent chips each round
+    pub loans_enabled: bool,
+    pub loan_limit: u32,            // Most a player can owe the bank at once
+    pub loan_interest_percent: u32, // Charged on outstanding debt each round
+    pub trading_enabled: bool,      // Let players swap paintings and chips between rounds
+    pub sell_back_enabled: bool,
+    pub sell_back_basis: SellBackBasis,
+    pub sell_back_percent: u32, // Share of the basis the bank pays out
+    pub sell_back_destination: SellBackDestination,
+    pub market_enabled: bool, // Speculative market: best-selling artists gain value each season
+    pub season_length: usize, // Rounds per season
+    pub secret_objectives: bool, // Deal each player a hidden bonus goal
+    pub scoring: ScoringMode, // House rule that turns a collection into points
+    pub tie_breaker: TieBreaker,
+    pub chips_per_point: u32, // Leftover chips worth one point at the end (0 = chips don't score)
+    pub teams_enabled: bool,  // Players are grouped into teams that win or lose together
+    pub team_scoring: TeamScoring,
+    pub deck_strategy: DeckStrategy,
+    pub deck_movements: Vec<Movement>, // Only paintings from these movements are dealt (empty = all)
+    pub focus_artists: Vec<Artist>,    // Guaranteed in the deck by the Guaranteed strategy
+    pub focus_movements: Vec<Movement>,
+    pub pieces_per_focus: usize, // Guaranteed pieces per focus artist or movement
+    pub star_weights: [u32; 3],  // Relative share of 1, 2 and 3 star paintings for StarTarget
+    pub seed: Option<u64>,       // Fixed seed for reproducible shuffles (None = random)
+    pub preview_count: usize,    // Upcoming paintings revealed to everyone (0 = hidden deck)
+    pub blind_lot_percent: u32,  // Chance each painting is auctioned blind (0 = never)
+    pub blind_hides: BlindHides,
+    pub blind_reveal: BlindReveal,
+    pub forgery_percent: u32, // Share of the deck secretly forged (0 = no forgeries)
+    pub forgery_penalty: u32, // Points lost per forgery exposed at the end (0 = just worthless)
+    pub authentication_enabled: bool,
+    pub authentication_cost: u32, // Chips to learn whether an owned painting is genuine
 }
 
 impl Default for GameConfig {
     fn default() -> Self {
         Self {
+            game_mode: GameMode::BankAuction,
             starting_chips: 100,
             bid_timer_seconds: 30,
             num_rounds: 10, // Default to 10 rounds
+            auction_type: AuctionType::Open,
+            second_price_minimum: 1,
+            lot_size: 1,
+            dutch_start_price: 60,
+            dutch_price_step: 2,
+            dutch_floor_price: 4,
+            dutch_floor_rule: FloorRule::Discard,
+            turn_timer_seconds: 15,
+            soft_close_window_seconds: 10,
+            soft_close_extension_seconds: 10,
+            max_round_seconds: 120,
+            min_increment: 1,
+            increment_rule: IncrementRule::Flat,
+            reserve_per_star: 0,
+            round_stipend: 0,
+            interest_percent: 0,
+            loans_enabled: false,
+            loan_limit: 50,
+            loan_interest_percent: 10,
+            trading_enabled: false,
+            sell_back_enabled: false,
+            sell_back_basis: SellBackBasis::Stars,
+            sell_back_percent: 50,
+            sell_back_destination: SellBackDestination::Discard,
+            market_enabled: false,
+            season_length: 5,
+            secret_objectives: false,
+            scoring: ScoringMode::MaxBonus,
+            tie_breaker: TieBreaker::Shared,
+            chips_per_point: 0,
+            teams_enabled: false,
+            team_scoring: TeamScoring::SumScores,
+            deck_strategy: DeckStrategy::Random,
+            deck_movements: Vec::new(),
+            focus_artists: Vec::new(),
+            focus_movements: Vec::new(),
+            pieces_per_focus: 2,
+            star_weights: [1, 1, 1],
+            seed: None,
+            preview_count: 0,
+            blind_lot_percent: 0,
+            blind_hides: BlindHides::OneAttribute,
+            blind_reveal: BlindReveal::Everyone,
+            forgery_percent: 0,
+            forgery_penalty: 0,
+            authentication_enabled: false,
+            authentication_cost: 10,
         }
     }
 }
 
+/// Outcome of a resolved round, kept so the bids can be revealed afterwards
+#[derive(Debug, Clone)]
+pub struct RoundResult {
+    pub round: usize,
+    pub auction_type: AuctionType,
+    pub lot: Vec<ArtPiece>,
+    pub bids: Vec<Bid>, // Sorted best bid first
+    pub winner_id: Option<String>,
+    pub seller_id: Option<String>, // Player who received the proceeds, if any
+    pub price_paid: u32,
+    pub reserved: bool,     // Unsold Dutch lot set aside for the reserve
+    pub reserve_price: u32, // Lowest bid the lot would accept
+}
+
+/// Why a painting ended up in the discard pile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscardReason {
+    /// Nobody bid on it
+    NoBids,
+    /// Nobody met its reserve price
+    Unsold,
+    /// A player sold it back to the bank
+    SoldBack,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiscardedPiece {
+    pub art: ArtPiece,
+    pub round: usize,
+    pub reason: DiscardReason,
+}
+
 pub struct Game {
     #[allow(dead_code)]
     pub id: String,
@@ -44,10 +445,32 @@ pub struct Game {
     pub state: GameState,
     pub config: GameConfig,
     pub players: HashMap<String, Player>,
+    pub join_order: Vec<String>, // Player ids in the order they joined
     pub deck: Vec<ArtPiece>,
-    pub current_art: Option<ArtPiece>,
+    pub current_art: Vec<ArtPiece>, // The lot up for auction this round
+    pub seller_id: Option<String>,  // Auctioneer of the current round in player-auctioneer games
     pub current_bids: Vec<Bid>,
-    pub discard_pile: Vec<ArtPiece>,
+    pub proxy_bids: Vec<ProxyBid>, // Hidden maximums, answered automatically as bids arrive
+    pub discard_pile: Vec<DiscardedPiece>,
+    pub reserve_pile: Vec<ArtPiece>,
+    pub reserve_dealt: bool,        // Reserve pieces only get one more chance
+    pub current_price: Option<u32>, // Asking price of a running Dutch round
+    pub turn_order: Vec<String>,    // Seating order for once-around rounds
+    pub turn_index: usize,          // How many players have acted this round
+    pub passed_players: HashSet<String>,
+    pub last_round: Option<RoundResult>,
+    pub round_started_at: Option<DateTime<Utc>>,
+    pub trade_offers: Vec<TradeOffer>, // Open offers, cleared when the next round starts
+    pub season: usize,
+    pub season_sales: Vec<(Artist, u32)>, // Pieces sold this season, in order of first sale
+    pub season_blind_sales: Vec<Artist>, // Artists of this season's sales still hidden from the table
+    pub market_values: HashMap<Artist, u32>, // Accumulated value per piece of each artist
+    pub last_season_awards: Vec<(Artist, u32)>,
+    pub next_trade_id: usize,
+    pub seed: Option<u64>, // Seed the game's shuffles used, recorded when it starts
+    pub hidden_art: HashMap<usize, HiddenAttributes>, // Blind paintings not yet revealed, by art id
+    pub art_handles: HashMap<usize, u64>, // Opaque handles forms use in place of art ids, by art id
+    pub forgeries: HashSet<usize>, // Art ids of forged paintings, secret until the game ends
     #[allow(dead_code)]
     pub timer_handle: Option<JoinHandle<()>>,
 }
@@ -60,10 +483,32 @@ impl Game {
             state: GameState::Lobby,
             config: GameConfig::default(),
             players: HashMap::new(),
+            join_order: Vec::new(),
             deck: Vec::new(),
-            current_art: None,
+            current_art: Vec::new(),
+            seller_id: None,
             current_bids: Vec::new(),
+            proxy_bids: Vec::new(),
             discard_pile: Vec::new(),
+            reserve_pile: Vec::new(),
+            reserve_dealt: false,
+            current_price: None,
+            turn_order: Vec::new(),
+            turn_index: 0,
+            passed_players: HashSet::new(),
+            last_round: None,
+            round_started_at: None,
+            trade_offers: Vec::new(),
+            season: 1,
+            season_sales: Vec::new(),
+            season_blind_sales: Vec::new(),
+            market_values: HashMap::new(),
+            last_season_awards: Vec::new(),
+            next_trade_id: 0,
+            seed: None,
+            hidden_art: HashMap::new(),
+            art_handles: HashMap::new(),
+            forgeries: HashSet::new(),
             timer_handle: None,
         }
     }
@@ -77,6 +522,7 @@ impl Game {
             return Err("Player already in game".to_string());
         }
 
+        self.join_order.push(player.id.clone());
         self.players.insert(player.id.clone(), player);
         Ok(())
     }
@@ -85,12 +531,329 @@ impl Game {
         self.host_id == player_id
     }
 
+    /// Reveal the next lot from the top of the deck
     pub fn deal_next_art(&mut self) {
-        self.current_art = self.deck.pop();
+        let lot_size = self.config.lot_size.clamp(1, self.deck.len().max(1));
+        self.current_art = self
+            .deck
+            .split_off(self.deck.len().saturating_sub(lot_size));
+    }
+
+    /// The next paintings `deal_next_art` will reveal, up to the preview count,
+    /// each paired with how many rounds away its lot is
+    pub fn upcoming_art(&self) -> Vec<(usize, &ArtPiece)> {
+        let lot_size = self.config.lot_size.max(1);
+        self.deck
+            .rchunks(lot_size)
+            .enumerate()
+            .flat_map(|(lot, pieces)| pieces.iter().map(move |art| (lot + 1, art)))
+            .take(self.config.preview_count)
+            .collect()
+    }
+
+    /// A painting as `viewer_id` sees it. Blind paintings stay masked until
+    /// revealed to everyone, or for good to anyone but the player holding them.
+    pub fn masked_art(&self, art: &ArtPiece, viewer_id: Option<&str>) -> MaskedArt {
+        let holds_it = viewer_id
+            .and_then(|id| self.players.get(id))
+            .is_some_and(|p| p.owns_art(art.id) || p.hand.iter().any(|a| a.id == art.id));
+        let hidden = self.hidden_art.get(&art.id).filter(|_| !holds_it);
+        art.masked(hidden.copied())
+    }
+
+    /// The handle a form refers to a painting by. Art ids index the art database,
+    /// so sending one would tell anyone who looks what a blind painting is.
+    pub fn art_handle(&self, art_id: usize) -> Option<u64> {
+        self.art_handles.get(&art_id).copied()
+    }
+
+    /// The painting a form's handle refers to
+    pub fn art_id_for_handle(&self, handle: u64) -> Option<usize> {
+        self.art_handles
+            .iter()
+            .find(|(_, h)| **h == handle)
+            .map(|(id, _)| *id)
     }
 
+    pub fn is_seller(&self, player_id: &str) -> bool {
+        self.seller_id.as_deref() == Some(player_id)
+    }
+
+    /// Paintings still to be auctioned, from the deck or players' hands
+    pub fn has_art_remaining(&self) -> bool {
+        !self.deck.is_empty() || self.players.values().any(|p| !p.hand.is_empty())
+    }
+
+    /// Next seller in seating order after the current one, skipping empty hands
+    pub fn next_seller(&self) -> Option<String> {
+        let seats = self.turn_order.len();
+        let start = self
+            .seller_id
+            .as_ref()
+            .and_then(|id| self.turn_order.iter().position(|p| p == id))
+            .map(|i| i + 1)
+            .unwrap_or(0);
+
+        (0..seats)
+            .map(|offset| &self.turn_order[(start + offset) % seats])
+            .find(|id| self.players.get(*id).is_some_and(|p| !p.hand.is_empty()))
+            .cloned()
+    }
+
+    /// Highest bid of the round; ties go to whoever bid first
     pub fn get_highest_bid(&self) -> Option<&Bid> {
-        self.current_bids.iter().max_by_key(|b| b.amount)
+        // Bids are stored in arrival order, so scanning in reverse also settles identical timestamps
+        self.current_bids
+            .iter()
+            .rev()
+            .max_by(|a, b| a.amount.cmp(&b.amount).then(b.timestamp.cmp(&a.timestamp)))
+    }
+
+    /// Lowest acceptable bid for the current lot: stars times the per-star reserve.
+    /// Dutch rounds rely on their floor price instead.
+    pub fn reserve_price(&self) -> u32 {
+        if self.config.auction_type == AuctionType::Dutch {
+            return 0;
+        }
+
+        // A hidden star value can't be given away by the reserve
+        let stars: u32 = self
+            .current_art
+            .iter()
+            .map(|a| {
+                self.masked_art(a, None)
+                    .stars
+                    .map_or(BLIND_STARS, u32::from)
+            })
+            .sum();
+        stars * self.config.reserve_per_star
+    }
+
+    /// Smallest raise allowed over a high bid of `amount`
+    pub fn bid_increment(&self, amount: u32) -> u32 {
+        let increment = match self.config.increment_rule {
+            IncrementRule::Flat => self.config.min_increment,
+            IncrementRule::Percent => amount
+                .saturating_mul(self.config.min_increment)
+                .div_ceil(100),
+        };
+        increment.max(1)
+    }
+
+    /// Smallest bid that would currently be accepted. Sealed bids only have to
+    /// meet the reserve, since the other bids are hidden.
+    pub fn minimum_bid(&self) -> u32 {
+        let floor = self.reserve_price().max(1);
+        if self.config.auction_type.is_sealed() {
+            return floor;
+        }
+
+        match self.get_highest_bid() {
+            Some(bid) => (bid.amount + self.bid_increment(bid.amount)).max(floor),
+            None => floor,
+        }
+    }
+
+    pub fn get_proxy_bid(&self, player_id: &str) -> Option<&ProxyBid> {
+        self.proxy_bids.iter().find(|p| p.player_id == player_id)
+    }
+
+    /// Trading happens while the game waits for the host to start the next round
+    pub fn is_trading_open(&self) -> bool {
+        self.config.trading_enabled
+            && matches!(
+                self.state,
+                GameState::Active {
+                    timer_ends_at: None,
+                    ..
+                }
+            )
+    }
+
+    /// What the bank pays for a piece from this player's collection
+    pub fn sell_back_price(&self, player: &Player, art: &ArtPiece) -> u32 {
+        let basis = match (
+            self.config.sell_back_basis,
+            player.acquisitions.get(&art.id),
+        ) {
+            (SellBackBasis::PurchasePrice, Some(acquisition)) => {
+                acquisition.price / acquisition.lot_size.max(1) as u32
+            }
+            // Pieces that weren't bought (e.g. traded for) fall back to their star value
+            _ => art.stars as u32 * STAR_VALUE_CHIPS,
+        };
+        basis * self.config.sell_back_percent / 100
+    }
+
+    /// Count a sold lot towards this season's artist rankings
+    pub fn record_sale(&mut self, lot: &[ArtPiece]) {
+        if !self.config.market_enabled {
+            return;
+        }
+
+        for art in lot {
+            if self.hidden_art.get(&art.id).is_some_and(|h| h.artist) {
+                self.season_blind_sales.push(art.artist);
+            }
+            match self.season_sales.iter_mut().find(|(a, _)| *a == art.artist) {
+                Some((_, count)) => *count += 1,
+                None => self.season_sales.push((art.artist, 1)),
+            }
+        }
+    }
+
+    /// This season's sales the table can see: each artist's count without the
+    /// blind pieces still hiding who painted them, and how many of those there are
+    pub fn visible_season_sales(&self) -> (Vec<(Artist, u32)>, usize) {
+        let visible = self
+            .season_sales
+            .iter()
+            .map(|(artist, count)| {
+                let blind = self
+                    .season_blind_sales
+                    .iter()
+                    .filter(|a| *a == artist)
+                    .count() as u32;
+                (*artist, count - blind)
+            })
+            .filter(|(_, count)| *count > 0)
+            .collect();
+        (visible, self.season_blind_sales.len())
+    }
+
+    /// What a player's pieces are worth on the art market
+    pub fn market_bonus(&self, player: &Player) -> u32 {
+        player
+            .collection
+            .iter()
+            .map(|art| self.market_values.get(&art.artist).copied().unwrap_or(0))
+            .sum()
+    }
+
+    /// Collection score plus market value and leftover chips, less any penalty
+    /// for unpaid debt or exposed forgeries
+    pub fn final_score(&self, player: &Player) -> u32 {
+        (player.calculate_score(self.config.scoring)
+            + self.market_bonus(player)
+            + self.chip_points(player))
+        .saturating_sub(player.debt_penalty() + self.forgery_penalty(player))
+    }
+
+    pub fn is_forgery(&self, art_id: usize) -> bool {
+        self.forgeries.contains(&art_id)
+    }
+
+    /// Points lost for the forgeries exposed in a player's collection
+    pub fn forgery_penalty(&self, player: &Player) -> u32 {
+        player.forgeries.len() as u32 * self.config.forgery_penalty
+    }
+
+    /// Points for chips left over once debt is paid off
+    pub fn chip_points(&self, player: &Player) -> u32 {
+        if self.config.chips_per_point == 0 {
+            return 0;
+        }
+        let leftover = (player.chips as i64 - player.debt as i64).max(0) as u32;
+        leftover / self.config.chips_per_point
+    }
+
+    /// Team numbers in use, in order
+    pub fn teams(&self) -> Vec<u8> {
+        let mut teams: Vec<u8> = self.players.values().filter_map(|p| p.team).collect();
+        teams.sort_unstable();
+        teams.dedup();
+        teams
+    }
+
+    /// Players on a team, by name
+    pub fn team_members(&self, team: u8) -> Vec<&Player> {
+        let mut members: Vec<&Player> = self
+            .players
+            .values()
+            .filter(|p| p.team == Some(team))
+            .collect();
+        members.sort_by(|a, b| a.name.cmp(&b.name));
+        members
+    }
+
+    /// Whether two different players are on the same team
+    pub fn are_teammates(&self, player_id: &str, other_id: &str) -> bool {
+        let team = |id: &str| self.players.get(id).and_then(|p| p.team);
+        self.config.teams_enabled
+            && player_id != other_id
+            && team(player_id).is_some()
+            && team(player_id) == team(other_id)
+    }
+
+    /// A team's combined score, objectives included
+    pub fn team_score(&self, team: u8) -> u32 {
+        let members = self.team_members(team);
+        match self.config.team_scoring {
+            TeamScoring::SumScores => members
+                .iter()
+                .map(|p| self.final_score(p) + self.objective_bonus(p))
+                .sum(),
+            TeamScoring::MergeCollections => {
+                let collection: Vec<ArtPiece> = members
+                    .iter()
+                    .flat_map(|p| p.collection.iter().cloned())
+                    .collect();
+                let extras: u32 = members
+                    .iter()
+                    .map(|p| self.market_bonus(p) + self.chip_points(p) + self.objective_bonus(p))
+                    .sum();
+                let penalty: u32 = members
+                    .iter()
+                    .map(|p| p.debt_penalty() + self.forgery_penalty(p))
+                    .sum();
+                (self.config.scoring.rule().score(&collection) + extras).saturating_sub(penalty)
+            }
+        }
+    }
+
+    /// Points a player's secret objective is worth right now
+    pub fn objective_bonus(&self, player: &Player) -> u32 {
+        match &player.objective {
+            Some(objective) if objective.is_met(player) => objective.bonus,
+            _ => 0,
+        }
+    }
+
+    pub fn get_player_bid(&self, player_id: &str) -> Option<&Bid> {
+        self.current_bids.iter().find(|b| b.player_id == player_id)
+    }
+
+    /// Player whose turn it is in a running once-around round.
+    /// The starting seat rotates by one each round.
+    pub fn current_turn_player(&self) -> Option<&String> {
+        if self.config.auction_type != AuctionType::OnceAround
+            || self.turn_index >= self.turn_order.len()
+        {
+            return None;
+        }
+
+        match self.state {
+            GameState::Active {
+                round,
+                timer_ends_at: Some(_),
+            } => {
+                let start = round.saturating_sub(1) % self.turn_order.len();
+                self.turn_order
+                    .get((start + self.turn_index) % self.turn_order.len())
+            }
+            _ => None,
+        }
+    }
+
+    /// True when a round is running and its clock has run out
+    pub fn is_round_expired(&self) -> bool {
+        matches!(
+            self.state,
+            GameState::Active {
+                timer_ends_at: Some(_),
+                ..
+            }
+        ) && self.calculate_remaining_seconds() <= 0
     }
 
     pub fn calculate_remaining_seconds(&self) -> i64 {
@@ -1,9 +1,19 @@
 pub mod art;
 pub mod bid;
 pub mod game;
+pub mod objective;
 pub mod player;
+pub mod scoring;
+pub mod trade;
 
-pub use art::{ArtPiece, Artist, Movement};
-pub use bid::Bid;
-pub use game::{Game, GameState};
-pub use player::Player;
+pub use art::{ArtPiece, Artist, HiddenAttributes, MaskedArt, Movement};
+pub use bid::{Bid, ProxyBid};
+pub use game::{
+    AuctionType, BLIND_STARS, BlindHides, BlindReveal, DeckStrategy, DiscardReason, DiscardedPiece,
+    FloorRule, Game, GameConfig, GameMode, GameState, IncrementRule, MARKET_AWARDS, RoundResult,
+    STAR_VALUE_CHIPS, SellBackBasis, SellBackDestination, TeamScoring, TieBreaker,
+};
+pub use objective::{Objective, ObjectiveGoal};
+pub use player::{Acquisition, DEBT_CHIPS_PER_POINT, Player};
+pub use scoring::{ChosenBonus, ScoreBreakdown, ScoreGroup, ScoringMode, ScoringRule};
+pub use trade::TradeOffer;
@@ -0,0 +1,62 @@
+use super::art::Movement;
+use super::player::Player;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// What a player has to achieve by the end of the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ObjectiveGoal {
+    /// Own at least `count` pieces from a movement
+    MovementCount { movement: Movement, count: usize },
+    /// Own a piece from a movement with at least this many stars
+    StarredPiece { movement: Movement, stars: u8 },
+    /// Own pieces from at least this many different movements
+    DistinctMovements(usize),
+    /// Own at least this many pieces by a single artist
+    SameArtist(usize),
+    /// Own at least this many pieces in total
+    CollectionSize(usize),
+    /// Finish with more than this many chips, net of debt
+    ChipsAbove(i32),
+}
+
+/// A secret goal dealt to a player at the start of the game
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Objective {
+    pub id: usize,
+    pub description: &'static str,
+    pub goal: ObjectiveGoal,
+    pub bonus: u32, // Points awarded at the end if the goal is met
+}
+
+impl Objective {
+    pub fn is_met(&self, player: &Player) -> bool {
+        let collection = &player.collection;
+        match self.goal {
+            ObjectiveGoal::MovementCount { movement, count } => {
+                collection.iter().filter(|a| a.movement == movement).count() >= count
+            }
+            ObjectiveGoal::StarredPiece { movement, stars } => collection
+                .iter()
+                .any(|a| a.movement == movement && a.stars >= stars),
+            ObjectiveGoal::DistinctMovements(count) => {
+                collection
+                    .iter()
+                    .map(|a| a.movement)
+                    .collect::<HashSet<_>>()
+                    .len()
+                    >= count
+            }
+            ObjectiveGoal::SameArtist(count) => {
+                let mut per_artist = HashMap::new();
+                for art in collection {
+                    *per_artist.entry(art.artist).or_insert(0) += 1;
+                }
+                per_artist.values().any(|&n| n >= count)
+            }
+            ObjectiveGoal::CollectionSize(count) => collection.len() >= count,
+            // Borrowed chips don't count towards the goal
+            ObjectiveGoal::ChipsAbove(chips) => player.chips - player.debt as i32 > chips,
+        }
+    }
+}
@@ -1,6 +1,19 @@
-use super::art::{ArtPiece, Artist, Movement};
+use super::art::ArtPiece;
+use super::objective::Objective;
+use super::scoring::{ScoreBreakdown, ScoringMode};
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// How a piece ended up in a player's collection
+#[derive(Debug, Clone, Serialize)]
+pub struct Acquisition {
+    pub round: usize,
+    pub lot_size: usize,
+    pub price: u32, // Paid for the whole lot
+}
+
+/// Chips of unpaid debt that cost one point at the end of the game
+pub const DEBT_CHIPS_PER_POINT: u32 = 5;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Player {
@@ -8,6 +21,13 @@ pub struct Player {
     pub name: String,
     pub chips: i32, // Allow negative for display, but prevent negative bids
     pub collection: Vec<ArtPiece>,
+    pub hand: Vec<ArtPiece>, // Paintings waiting to be auctioned by this player
+    pub acquisitions: HashMap<usize, Acquisition>, // Keyed by art piece id
+    pub debt: u32,           // Chips borrowed from the bank
+    pub objective: Option<Objective>, // Secret goal, revealed at the end
+    pub team: Option<u8>,    // Team number, when playing in teams
+    pub authenticated: HashSet<usize>, // Paintings this player paid to have checked
+    pub forgeries: Vec<ArtPiece>, // Fakes taken out of the collection when exposed at the end
 }
 
 impl Player {
@@ -17,54 +37,65 @@ impl Player {
             name,
             chips: starting_chips as i32,
             collection: Vec::new(),
+            hand: Vec::new(),
+            acquisitions: HashMap::new(),
+            debt: 0,
+            objective: None,
+            team: None,
+            authenticated: HashSet::new(),
+            forgeries: Vec::new(),
         }
     }
 
-    pub fn calculate_score(&self) -> u32 {
-        let artist_score = self.calculate_artist_bonus();
-        let movement_score = self.calculate_movement_bonus();
-        // Use max, not sum - pieces count for both bonuses but we take the higher one
-        artist_score.max(movement_score)
+    /// Score the collection under the game's scoring rule
+    pub fn calculate_score(&self, scoring: ScoringMode) -> u32 {
+        scoring.rule().score(&self.collection)
+    }
+
+    /// Itemize the collection score by artist and movement
+    pub fn score_breakdown(&self, scoring: ScoringMode) -> ScoreBreakdown {
+        ScoreBreakdown::new(&self.collection, scoring)
     }
 
-    fn calculate_artist_bonus(&self) -> u32 {
-        // Group by artist: sum(stars) × count
-        let mut artist_groups: HashMap<Artist, Vec<u8>> = HashMap::new();
-        for art in &self.collection {
-            artist_groups.entry(art.artist).or_default().push(art.stars);
+    /// Add a won lot to the collection, remembering when and for how much
+    pub fn add_lot(&mut self, lot: Vec<ArtPiece>, round: usize, price: u32) {
+        let lot_size = lot.len();
+        for art in lot {
+            self.acquisitions.insert(
+                art.id,
+                Acquisition {
+                    round,
+                    lot_size,
+                    price,
+                },
+            );
+            self.collection.push(art);
         }
+    }
 
-        artist_groups
-            .values()
-            .map(|pieces| {
-                let sum: u32 = pieces.iter().map(|&s| s as u32).sum();
-                let count = pieces.len() as u32;
-                sum * count
-            })
-            .sum()
+    /// Points lost at the end for debt the player's chips can't cover
+    pub fn debt_penalty(&self) -> u32 {
+        let unpaid = (self.debt as i32 - self.chips).max(0) as u32;
+        unpaid.div_ceil(DEBT_CHIPS_PER_POINT)
     }
 
-    fn calculate_movement_bonus(&self) -> u32 {
-        // Group by movement: sum(stars) × count
-        let mut movement_groups: HashMap<Movement, Vec<u8>> = HashMap::new();
-        for art in &self.collection {
-            movement_groups
-                .entry(art.movement)
-                .or_default()
-                .push(art.stars);
-        }
+    pub fn owns_art(&self, art_id: usize) -> bool {
+        self.collection.iter().any(|a| a.id == art_id)
+    }
 
-        movement_groups
-            .values()
-            .map(|pieces| {
-                let sum: u32 = pieces.iter().map(|&s| s as u32).sum();
-                let count = pieces.len() as u32;
-                sum * count
-            })
-            .sum()
+    /// Remove pieces from the collection, e.g. to hand them over in a trade
+    pub fn take_art(&mut self, art_ids: &[usize]) -> Vec<ArtPiece> {
+        let (taken, kept) = std::mem::take(&mut self.collection)
+            .into_iter()
+            .partition(|a| art_ids.contains(&a.id));
+        self.collection = kept;
+        for art_id in art_ids {
+            self.acquisitions.remove(art_id);
+        }
+        taken
     }
 
     pub fn can_bid(&self, amount: u32) -> bool {
-        self.chips >= amount as i32 && amount > 0
+        amount > 0 && i32::try_from(amount).is_ok_and(|amount| amount <= self.chips)
     }
 }
@@ -0,0 +1,282 @@
+use super::art::{ArtPiece, Movement};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Every artist has this many paintings in the deck
+pub const ARTIST_SET_SIZE: usize = 3;
+
+/// Points for owning every painting by one artist
+pub const SET_COMPLETION_BONUS: u32 = 15;
+
+/// Points for each different movement in a collection
+pub const DIVERSITY_BONUS: u32 = 4;
+
+/// How a collection turns into points
+pub trait ScoringRule {
+    fn name(&self) -> &'static str;
+    fn description(&self) -> &'static str;
+    fn score(&self, collection: &[ArtPiece]) -> u32;
+
+    /// Which of the artist and movement bonuses this rule counts
+    fn chosen_bonus(&self, _artist_total: u32, _movement_total: u32) -> ChosenBonus {
+        ChosenBonus::Neither
+    }
+}
+
+/// The bonus a scoring rule counted towards the total
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ChosenBonus {
+    Artist,
+    Movement,
+    Both,
+    Neither,
+}
+
+impl ChosenBonus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChosenBonus::Artist => "Artist bonus",
+            ChosenBonus::Movement => "Movement bonus",
+            ChosenBonus::Both => "Artist + movement bonus",
+            ChosenBonus::Neither => "No artist or movement bonus",
+        }
+    }
+}
+
+/// One artist or movement's share of a bonus
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScoreGroup {
+    pub name: &'static str,
+    pub stars: u32,
+    pub count: u32,
+    pub subtotal: u32, // stars × count
+}
+
+/// Itemized view of how a collection was scored
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScoreBreakdown {
+    pub rule: &'static str,
+    pub artists: Vec<ScoreGroup>,
+    pub movements: Vec<ScoreGroup>,
+    pub artist_total: u32,
+    pub movement_total: u32,
+    pub chosen: ChosenBonus,
+    pub total: u32, // Collection score under the rule
+}
+
+impl ScoreBreakdown {
+    pub fn new(collection: &[ArtPiece], scoring: ScoringMode) -> Self {
+        let rule = scoring.rule();
+        let artists = score_groups(collection, |a| a.artist, |a| a.artist.name());
+        let movements = score_groups(collection, |a| a.movement, |a| a.movement.name());
+        let artist_total = artists.iter().map(|g| g.subtotal).sum();
+        let movement_total = movements.iter().map(|g| g.subtotal).sum();
+
+        ScoreBreakdown {
+            rule: rule.name(),
+            artists,
+            movements,
+            artist_total,
+            movement_total,
+            chosen: rule.chosen_bonus(artist_total, movement_total),
+            total: rule.score(collection),
+        }
+    }
+}
+
+/// House rule chosen in the lobby
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScoringMode {
+    #[default]
+    MaxBonus,
+    SumBonus,
+    StarsOnly,
+    SetCompletion,
+    Diversity,
+}
+
+impl ScoringMode {
+    pub const ALL: [ScoringMode; 5] = [
+        ScoringMode::MaxBonus,
+        ScoringMode::SumBonus,
+        ScoringMode::StarsOnly,
+        ScoringMode::SetCompletion,
+        ScoringMode::Diversity,
+    ];
+
+    pub fn rule(&self) -> &'static dyn ScoringRule {
+        match self {
+            ScoringMode::MaxBonus => &MaxBonusRule,
+            ScoringMode::SumBonus => &SumBonusRule,
+            ScoringMode::StarsOnly => &StarsOnlyRule,
+            ScoringMode::SetCompletion => &SetCompletionRule,
+            ScoringMode::Diversity => &DiversityRule,
+        }
+    }
+}
+
+/// The classic rule: the better of the artist and movement bonuses
+pub struct MaxBonusRule;
+
+impl ScoringRule for MaxBonusRule {
+    fn name(&self) -> &'static str {
+        "Best Bonus"
+    }
+
+    fn description(&self) -> &'static str {
+        "For each artist and each movement, (sum of stars) × (number of pieces). You score whichever total is higher, artists or movements."
+    }
+
+    fn score(&self, collection: &[ArtPiece]) -> u32 {
+        // Use max, not sum - pieces count for both bonuses but we take the higher one
+        artist_bonus(collection).max(movement_bonus(collection))
+    }
+
+    fn chosen_bonus(&self, artist_total: u32, movement_total: u32) -> ChosenBonus {
+        if artist_total >= movement_total {
+            ChosenBonus::Artist
+        } else {
+            ChosenBonus::Movement
+        }
+    }
+}
+
+/// Artist and movement bonuses both count
+pub struct SumBonusRule;
+
+impl ScoringRule for SumBonusRule {
+    fn name(&self) -> &'static str {
+        "Both Bonuses"
+    }
+
+    fn description(&self) -> &'static str {
+        "For each artist and each movement, (sum of stars) × (number of pieces). You score the artist and movement totals added together."
+    }
+
+    fn score(&self, collection: &[ArtPiece]) -> u32 {
+        artist_bonus(collection) + movement_bonus(collection)
+    }
+
+    fn chosen_bonus(&self, _artist_total: u32, _movement_total: u32) -> ChosenBonus {
+        ChosenBonus::Both
+    }
+}
+
+/// Just the stars, no set bonuses
+pub struct StarsOnlyRule;
+
+impl ScoringRule for StarsOnlyRule {
+    fn name(&self) -> &'static str {
+        "Stars Only"
+    }
+
+    fn description(&self) -> &'static str {
+        "Every star in your collection is worth one point. No bonuses."
+    }
+
+    fn score(&self, collection: &[ArtPiece]) -> u32 {
+        total_stars(collection)
+    }
+}
+
+/// Stars, plus a bonus for each artist whose paintings are all in the collection
+pub struct SetCompletionRule;
+
+impl ScoringRule for SetCompletionRule {
+    fn name(&self) -> &'static str {
+        "Set Completion"
+    }
+
+    fn description(&self) -> &'static str {
+        "One point per star, plus 15 points for each artist whose 3 paintings you own."
+    }
+
+    fn score(&self, collection: &[ArtPiece]) -> u32 {
+        let complete_sets = group_counts(collection, |a| a.artist)
+            .values()
+            .filter(|&&count| count >= ARTIST_SET_SIZE)
+            .count() as u32;
+        total_stars(collection) + complete_sets * SET_COMPLETION_BONUS
+    }
+}
+
+/// Stars, plus a bonus for every different movement
+pub struct DiversityRule;
+
+impl ScoringRule for DiversityRule {
+    fn name(&self) -> &'static str {
+        "Diversity"
+    }
+
+    fn description(&self) -> &'static str {
+        "One point per star, plus 4 points for each different movement in your collection."
+    }
+
+    fn score(&self, collection: &[ArtPiece]) -> u32 {
+        let movements: HashSet<Movement> = collection.iter().map(|a| a.movement).collect();
+        total_stars(collection) + movements.len() as u32 * DIVERSITY_BONUS
+    }
+}
+
+fn total_stars(collection: &[ArtPiece]) -> u32 {
+    collection.iter().map(|a| a.stars as u32).sum()
+}
+
+/// Group by artist: sum(stars) × count
+fn artist_bonus(collection: &[ArtPiece]) -> u32 {
+    group_bonus(collection, |a| a.artist)
+}
+
+/// Group by movement: sum(stars) × count
+fn movement_bonus(collection: &[ArtPiece]) -> u32 {
+    group_bonus(collection, |a| a.movement)
+}
+
+fn group_bonus<K: Hash + Eq>(collection: &[ArtPiece], key: impl Fn(&ArtPiece) -> K) -> u32 {
+    score_groups(collection, key, |_| "")
+        .iter()
+        .map(|g| g.subtotal)
+        .sum()
+}
+
+/// Group pieces by a key, biggest subtotal first
+fn score_groups<K: Hash + Eq>(
+    collection: &[ArtPiece],
+    key: impl Fn(&ArtPiece) -> K,
+    name: impl Fn(&ArtPiece) -> &'static str,
+) -> Vec<ScoreGroup> {
+    let mut groups: HashMap<K, ScoreGroup> = HashMap::new();
+    for art in collection {
+        let group = groups.entry(key(art)).or_insert_with(|| ScoreGroup {
+            name: name(art),
+            stars: 0,
+            count: 0,
+            subtotal: 0,
+        });
+        group.stars += art.stars as u32;
+        group.count += 1;
+    }
+
+    let mut groups: Vec<ScoreGroup> = groups
+        .into_values()
+        .map(|g| ScoreGroup {
+            subtotal: g.stars * g.count,
+            ..g
+        })
+        .collect();
+    groups.sort_by(|a, b| b.subtotal.cmp(&a.subtotal).then(a.name.cmp(b.name)));
+    groups
+}
+
+fn group_counts<K: Hash + Eq>(
+    collection: &[ArtPiece],
+    key: impl Fn(&ArtPiece) -> K,
+) -> HashMap<K, usize> {
+    let mut counts = HashMap::new();
+    for art in collection {
+        *counts.entry(key(art)).or_insert(0) += 1;
+    }
+    counts
+}
@@ -0,0 +1,13 @@
+use serde::Serialize;
+
+/// A proposed swap of paintings and chips between two players
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeOffer {
+    pub id: usize,
+    pub from_id: String,
+    pub to_id: String,
+    pub offered_art: Vec<usize>, // Art piece ids from the proposer's collection
+    pub requested_art: Vec<usize>, // Art piece ids from the counterparty's collection
+    pub offered_chips: u32,
+    pub requested_chips: u32,
+}
@@ -19,6 +19,11 @@ pub fn create_router(state: AppState) -> Router {
             "/lobby/:game_id/configure",
             post(handlers::lobby::configure),
         )
+        .route(
+            "/lobby/:game_id/deck",
+            post(handlers::lobby::configure_deck),
+        )
+        .route("/lobby/:game_id/team", post(handlers::lobby::assign_team))
         .route("/lobby/:game_id/start", post(handlers::lobby::start_game))
         // Game view
         .route("/game/:game_id/play", get(handlers::game::game_view))
@@ -30,6 +35,11 @@ pub fn create_router(state: AppState) -> Router {
             "/game/:game_id/collection",
             get(handlers::game::collection_display),
         )
+        .route("/game/:game_id/trades", get(handlers::game::trade_panel))
+        .route(
+            "/game/:game_id/upcoming",
+            get(handlers::game::upcoming_lots),
+        )
         .route(
             "/game/:game_id/bidding-area",
             get(handlers::game::bidding_area),
@@ -40,7 +50,36 @@ pub fn create_router(state: AppState) -> Router {
         )
         // Game actions
         .route("/game/:game_id/bid", post(handlers::game::place_bid))
+        .route(
+            "/game/:game_id/proxy-bid",
+            post(handlers::game::place_proxy_bid),
+        )
         .route("/game/:game_id/pass", post(handlers::game::pass_bid))
+        .route("/game/:game_id/buy", post(handlers::game::buy_art))
+        .route("/game/:game_id/offer", post(handlers::game::offer_art))
+        .route(
+            "/game/:game_id/trade/propose",
+            post(handlers::game::propose_trade),
+        )
+        .route(
+            "/game/:game_id/trade/:trade_id/accept",
+            post(handlers::game::accept_trade),
+        )
+        .route(
+            "/game/:game_id/trade/:trade_id/reject",
+            post(handlers::game::reject_trade),
+        )
+        .route("/game/:game_id/sell", post(handlers::game::sell_art))
+        .route(
+            "/game/:game_id/authenticate",
+            post(handlers::game::authenticate_art),
+        )
+        .route("/game/:game_id/borrow", post(handlers::game::take_loan))
+        .route("/game/:game_id/repay", post(handlers::game::repay_loan))
+        .route(
+            "/game/:game_id/transfer",
+            post(handlers::game::transfer_chips),
+        )
         .route(
             "/game/:game_id/start-round",
             post(handlers::game::start_round),
@@ -1,5 +1,7 @@
-use crate::models::{ArtPiece, Artist, Movement};
+use crate::models::scoring::ARTIST_SET_SIZE;
+use crate::models::{ArtPiece, Artist, DeckStrategy, GameConfig, Movement};
 use once_cell::sync::Lazy;
+use rand::Rng;
 use rand::seq::SliceRandom;
 
 /// Database of 90 art pieces: 30 artists × 3 paintings, 10 movements × 9 paintings
@@ -673,17 +675,146 @@ pub static ART_PIECES: Lazy<Vec<ArtPiece>> = Lazy::new(|| {
 #[allow(dead_code)]
 pub fn get_game_deck(num_players: usize) -> Vec<ArtPiece> {
     let deck_size = (num_players * 5).min(90);
-    get_game_deck_by_count(deck_size)
+    shuffled_deck(ART_PIECES.clone(), deck_size, &mut rand::thread_rng())
 }
 
-/// Returns a shuffled subset of paintings for a game with a specific count
-pub fn get_game_deck_by_count(count: usize) -> Vec<ArtPiece> {
+fn shuffled_deck(mut pool: Vec<ArtPiece>, count: usize, rng: &mut impl Rng) -> Vec<ArtPiece> {
     let deck_size = count.clamp(1, 90); // At least 1, at most 90
-    let mut rng = rand::thread_rng();
+    pool.shuffle(rng);
+    pool.truncate(deck_size);
+    pool
+}
+
+/// Every artist, in database order
+pub fn all_artists() -> Vec<Artist> {
+    let mut artists: Vec<Artist> = Vec::new();
+    for art in ART_PIECES.iter() {
+        if !artists.contains(&art.artist) {
+            artists.push(art.artist);
+        }
+    }
+    artists
+}
+
+/// Every movement, in database order
+pub fn all_movements() -> Vec<Movement> {
+    let mut movements: Vec<Movement> = Vec::new();
+    for art in ART_PIECES.iter() {
+        if !movements.contains(&art.movement) {
+            movements.push(art.movement);
+        }
+    }
+    movements
+}
+
+/// Returns a shuffled deck for one lot per round, built with the configured strategy.
+/// Strategies that run short of paintings deal a smaller deck, and the game ends early.
+pub fn build_deck(config: &GameConfig, rng: &mut impl Rng) -> Vec<ArtPiece> {
+    let count = (config.num_rounds * config.lot_size.max(1)).clamp(1, 90);
+
+    let pool: Vec<ArtPiece> = ART_PIECES
+        .iter()
+        .filter(|a| config.deck_movements.is_empty() || config.deck_movements.contains(&a.movement))
+        .cloned()
+        .collect();
+
+    let mut deck = match config.deck_strategy {
+        DeckStrategy::Random => shuffled_deck(pool, count, rng),
+        DeckStrategy::Guaranteed => guaranteed_deck(pool, config, count, rng),
+        DeckStrategy::StarTarget => star_target_deck(pool, config.star_weights, count, rng),
+        DeckStrategy::CompleteSets => complete_sets_deck(pool, count, rng),
+    };
+
+    deck.shuffle(rng);
+    deck
+}
+
+/// Focus pieces first, topped up with random paintings
+fn guaranteed_deck(
+    mut pool: Vec<ArtPiece>,
+    config: &GameConfig,
+    count: usize,
+    rng: &mut impl Rng,
+) -> Vec<ArtPiece> {
+    pool.shuffle(rng);
+
+    let mut deck = Vec::new();
+    let mut take = |pool: &mut Vec<ArtPiece>, matches: &dyn Fn(&ArtPiece) -> bool| {
+        for _ in 0..config.pieces_per_focus {
+            match pool.iter().position(matches) {
+                Some(i) => deck.push(pool.swap_remove(i)),
+                None => break,
+            }
+        }
+    };
+    for artist in &config.focus_artists {
+        take(&mut pool, &|a| a.artist == *artist);
+    }
+    for movement in &config.focus_movements {
+        take(&mut pool, &|a| a.movement == *movement);
+    }
+
+    // Guarantees beyond the deck size are dropped at random
+    deck.shuffle(rng);
+    deck.truncate(count);
+    let missing = count - deck.len();
+    deck.extend(pool.into_iter().take(missing));
+    deck
+}
+
+/// Split the deck between star values by weight, topping up from any star if one runs out
+fn star_target_deck(
+    mut pool: Vec<ArtPiece>,
+    weights: [u32; 3],
+    count: usize,
+    rng: &mut impl Rng,
+) -> Vec<ArtPiece> {
+    pool.shuffle(rng);
+
+    let total_weight: u32 = weights.iter().sum::<u32>().max(1);
+    let mut deck = Vec::new();
+    for (i, weight) in weights.iter().enumerate() {
+        let stars = i as u8 + 1;
+        let target = (count * *weight as usize).div_ceil(total_weight as usize);
+        let mut taken = 0;
+        pool.retain(|art| {
+            if art.stars == stars && taken < target {
+                deck.push(art.clone());
+                taken += 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    deck.shuffle(rng);
+    deck.truncate(count);
+    let missing = count - deck.len();
+    deck.extend(pool.into_iter().take(missing));
+    deck
+}
 
-    let mut deck: Vec<ArtPiece> = ART_PIECES.clone();
-    deck.shuffle(&mut rng);
-    deck.truncate(deck_size);
+/// Whole artist sets, as many as fit in the deck.
+/// A game too short for one set gets part of one.
+fn complete_sets_deck(pool: Vec<ArtPiece>, count: usize, rng: &mut impl Rng) -> Vec<ArtPiece> {
+    let mut sets: Vec<Vec<ArtPiece>> = Vec::new();
+    for art in pool {
+        match sets.iter_mut().find(|set| set[0].artist == art.artist) {
+            Some(set) => set.push(art),
+            None => sets.push(vec![art]),
+        }
+    }
+    sets.retain(|set| set.len() == ARTIST_SET_SIZE);
+    sets.shuffle(rng);
 
+    let mut deck = Vec::new();
+    for set in sets {
+        if !deck.is_empty() && deck.len() + set.len() > count {
+            break;
+        }
+        deck.extend(set);
+    }
+    deck.truncate(count);
     deck
 }
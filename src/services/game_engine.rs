@@ -1,6 +1,62 @@
-use crate::models::{Bid, Game, GameState};
-use crate::services::art_database::get_game_deck_by_count;
+use crate::models::{
+    AuctionType, Bid, BlindHides, BlindReveal, DiscardReason, DiscardedPiece, FloorRule, Game,
+    GameMode, GameState, HiddenAttributes, MARKET_AWARDS, Player, ProxyBid, RoundResult,
+    SellBackDestination, TieBreaker,
+};
+use crate::services::art_database::build_deck;
+use crate::services::objectives::deal_objectives;
 use chrono::{Duration, Utc};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+
+/// Decide which paintings in the deck will be auctioned blind, and what they hide
+fn pick_blind_art(game: &Game, rng: &mut StdRng) -> HashMap<usize, HiddenAttributes> {
+    let mut hidden_art = HashMap::new();
+    for art in &game.deck {
+        if rng.gen_range(0..100) >= game.config.blind_lot_percent {
+            continue;
+        }
+        let hidden = match game.config.blind_hides {
+            BlindHides::Everything => HiddenAttributes {
+                artist: true,
+                movement: true,
+                stars: true,
+            },
+            BlindHides::OneAttribute => {
+                let pick = rng.gen_range(0..3);
+                HiddenAttributes {
+                    artist: pick == 0,
+                    movement: pick == 1,
+                    stars: pick == 2,
+                }
+            }
+        };
+        hidden_art.insert(art.id, hidden);
+    }
+    hidden_art
+}
+
+/// Secretly forge a share of the deck
+fn pick_forgeries(game: &Game, rng: &mut StdRng) -> HashSet<usize> {
+    let count = game.deck.len() * game.config.forgery_percent as usize / 100;
+    game.deck
+        .choose_multiple(rng, count)
+        .map(|art| art.id)
+        .collect()
+}
+
+/// Take every forgery out of the collections it ended up in, so it no longer scores
+fn expose_forgeries(game: &mut Game) {
+    for player in game.players.values_mut() {
+        let (fakes, genuine) = std::mem::take(&mut player.collection)
+            .into_iter()
+            .partition(|art| game.forgeries.contains(&art.id));
+        player.collection = genuine;
+        player.forgeries = fakes;
+    }
+}
 
 pub fn start_game(game: &mut Game) -> Result<(), String> {
     // Validate we're in lobby state
@@ -13,15 +69,64 @@ pub fn start_game(game: &mut Game) -> Result<(), String> {
         return Err("Need at least 2 players to start".to_string());
     }
 
-    // Generate deck: num_rounds paintings as configured
-    let num_rounds = game.config.num_rounds;
-    game.deck = get_game_deck_by_count(num_rounds);
+    if game.config.teams_enabled {
+        if game.players.values().any(|p| p.team.is_none()) {
+            return Err("Every player needs a team".to_string());
+        }
+        if game.teams().len() < 2 {
+            return Err("Need at least 2 teams to start".to_string());
+        }
+    }
+
+    // Every shuffle draws from one RNG, so replaying the seed with players joining
+    // in the same order replays the deck, the seating and the objectives
+    let seed = game.config.seed.unwrap_or_else(rand::random);
+    game.seed = Some(seed);
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    // Generate deck: one lot of paintings per round, built with the configured strategy
+    game.deck = build_deck(&game.config, &mut rng);
+    game.hidden_art = pick_blind_art(game, &mut rng);
+    game.forgeries = pick_forgeries(game, &mut rng);
+    // Handles don't come from the seed, so they can't be worked out from a replay
+    game.art_handles = game
+        .deck
+        .iter()
+        .map(|art| (art.id, rand::random()))
+        .collect();
+
+    // Seat players in a random order for turn-based rounds
+    game.turn_order = game.join_order.clone();
+    game.turn_order.shuffle(&mut rng);
+
+    if game.config.secret_objectives {
+        let mut players: Vec<&mut Player> = game.players.values_mut().collect();
+        let join_order = &game.join_order;
+        players.sort_by_key(|p| join_order.iter().position(|id| *id == p.id));
+        deal_objectives(players.into_iter(), &mut rng);
+    }
+
+    if game.config.game_mode == GameMode::PlayerAuctioneer {
+        // Deal the paintings round-robin into hands; the first seller picks what to auction
+        let deck = std::mem::take(&mut game.deck);
+        let seats = game.turn_order.len();
+        for (i, art) in deck.into_iter().enumerate() {
+            if let Some(player) = game.players.get_mut(&game.turn_order[i % seats]) {
+                player.hand.push(art);
+            }
+        }
 
-    // Deal first art piece
-    game.deal_next_art();
+        game.seller_id = game.next_seller();
+        if game.seller_id.is_none() {
+            return Err("Failed to deal paintings".to_string());
+        }
+    } else {
+        // Deal first art piece
+        game.deal_next_art();
 
-    if game.current_art.is_none() {
-        return Err("Failed to deal first art piece".to_string());
+        if game.current_art.is_empty() {
+            return Err("Failed to deal first art piece".to_string());
+        }
     }
 
     // Transition to Active state (waiting for host to start first round)
@@ -46,6 +151,24 @@ pub fn place_bid(game: &mut Game, player_id: &str, amount: u32) -> Result<(), St
         return Err("Waiting for host to start the round".to_string());
     }
 
+    if game.config.auction_type == AuctionType::Dutch {
+        return Err("Dutch auctions take a Buy, not a bid".to_string());
+    }
+
+    if game.config.auction_type == AuctionType::OnceAround
+        && game.current_turn_player().map(String::as_str) != Some(player_id)
+    {
+        return Err("It's not your turn".to_string());
+    }
+
+    if game.is_seller(player_id) {
+        return Err("You can't bid on your own painting".to_string());
+    }
+
+    if game.passed_players.contains(player_id) {
+        return Err("You already passed on this piece".to_string());
+    }
+
     // Validate player exists
     let player = game.players.get(player_id).ok_or("Player not found")?;
 
@@ -54,16 +177,48 @@ pub fn place_bid(game: &mut Game, player_id: &str, amount: u32) -> Result<(), St
         return Err("Insufficient chips or invalid bid amount".to_string());
     }
 
-    // Validate bid is higher than current highest
-    if let Some(highest_bid) = game.get_highest_bid()
-        && amount <= highest_bid.amount
-    {
+    let reserve_price = game.reserve_price();
+    if amount < reserve_price {
         return Err(format!(
-            "Bid must be higher than current bid of {}",
-            highest_bid.amount
+            "Bid must be at least the reserve price of {}",
+            reserve_price
         ));
     }
 
+    // Sealed bids are hidden and final: one per player, timer keeps running
+    if game.config.auction_type.is_sealed() {
+        if game.get_player_bid(player_id).is_some() {
+            return Err("You have already placed your sealed bid".to_string());
+        }
+
+        game.current_bids.push(Bid {
+            player_id: player_id.to_string(),
+            amount,
+            timestamp: Utc::now(),
+        });
+
+        return Ok(());
+    }
+
+    // Validate bid is higher than current highest
+    if let Some(highest_bid) = game.get_highest_bid() {
+        if amount <= highest_bid.amount {
+            return Err(format!(
+                "Bid must be higher than current bid of {}",
+                highest_bid.amount
+            ));
+        }
+
+        let increment = game.bid_increment(highest_bid.amount);
+        if amount < highest_bid.amount + increment {
+            return Err(format!(
+                "Raises must be at least {} chips - bid {} or more",
+                increment,
+                highest_bid.amount + increment
+            ));
+        }
+    }
+
     // Add bid
     let bid = Bid {
         player_id: player_id.to_string(),
@@ -72,58 +227,293 @@ pub fn place_bid(game: &mut Game, player_id: &str, amount: u32) -> Result<(), St
     };
     game.current_bids.push(bid);
 
-    // Reset timer (round is already started, just reset the timer)
-    let timer_duration = game.config.bid_timer_seconds as i64;
-    if let GameState::Active { round, .. } = game.state {
-        game.state = GameState::Active {
-            round,
-            timer_ends_at: Some(Utc::now() + Duration::seconds(timer_duration)),
-        };
+    // Once-around rounds move on to the next player instead of resetting the clock
+    if game.config.auction_type == AuctionType::OnceAround {
+        advance_turn(game);
+        return Ok(());
+    }
+
+    // Standing maximums get a chance to answer
+    resolve_proxy_bids(game);
+    after_open_bid(game);
+
+    Ok(())
+}
+
+/// Set a hidden maximum bid. The engine raises on the player's behalf by the
+/// minimum increment, up to that ceiling, whenever someone outbids them.
+pub fn place_proxy_bid(game: &mut Game, player_id: &str, max_amount: u32) -> Result<(), String> {
+    if game.config.auction_type != AuctionType::Open {
+        return Err("Maximum bids are only available in open auctions".to_string());
+    }
+
+    if !matches!(
+        game.state,
+        GameState::Active {
+            timer_ends_at: Some(_),
+            ..
+        }
+    ) {
+        return Err("Waiting for host to start the round".to_string());
+    }
+
+    if game.is_seller(player_id) {
+        return Err("You can't bid on your own painting".to_string());
+    }
+
+    if game.passed_players.contains(player_id) {
+        return Err("You already passed on this piece".to_string());
+    }
+
+    let player = game.players.get(player_id).ok_or("Player not found")?;
+    if !player.can_bid(max_amount) {
+        return Err("Insufficient chips or invalid bid amount".to_string());
+    }
+
+    // The high bidder only needs a ceiling above their own bid
+    let minimum = match game.get_highest_bid() {
+        Some(bid) if bid.player_id == player_id => bid.amount + 1,
+        _ => game.minimum_bid(),
+    };
+    if max_amount < minimum {
+        return Err(format!("Your maximum must be at least {}", minimum));
+    }
+
+    game.proxy_bids.retain(|p| p.player_id != player_id);
+    game.proxy_bids.push(ProxyBid {
+        player_id: player_id.to_string(),
+        max_amount,
+        timestamp: Utc::now(),
+    });
+
+    let bid_count = game.current_bids.len();
+    resolve_proxy_bids(game);
+    if game.current_bids.len() > bid_count {
+        after_open_bid(game);
     }
 
     Ok(())
 }
 
+/// Clock and early-close bookkeeping after an open round gets a new high bid
+fn after_open_bid(game: &mut Game) {
+    // Late bids push the close back a little instead of restarting the clock
+    extend_soft_close(game);
+
+    // Nobody left to challenge this bid - no need to wait out the clock
+    if all_rivals_passed(game) {
+        close_round(game);
+    }
+}
+
+/// Let standing maximums answer the current high bid, eBay-style: the higher
+/// ceiling (or the earlier one, on a tie) leads, one increment above the other.
+/// Every pass settles one duel and retires the losing proxy, so this always ends.
+fn resolve_proxy_bids(game: &mut Game) {
+    loop {
+        let minimum = game.minimum_bid();
+        let high_bid = game
+            .get_highest_bid()
+            .map(|b| (b.player_id.clone(), b.amount));
+        let high_bidder = high_bid.as_ref().map(|(id, _)| id);
+
+        // Strongest proxy that could still take the lead
+        let Some(challenger) = game
+            .proxy_bids
+            .iter()
+            .filter(|p| Some(&p.player_id) != high_bidder)
+            .filter(|p| !game.passed_players.contains(&p.player_id))
+            .filter(|p| proxy_ceiling(game, p) >= minimum)
+            .max_by(|a, b| {
+                proxy_ceiling(game, a)
+                    .cmp(&proxy_ceiling(game, b))
+                    .then(b.timestamp.cmp(&a.timestamp))
+            })
+            .cloned()
+        else {
+            break;
+        };
+        let challenger_ceiling = proxy_ceiling(game, &challenger);
+
+        let defender = high_bidder.and_then(|id| game.get_proxy_bid(id)).cloned();
+        let defender_ceiling = match (&defender, &high_bid) {
+            (Some(defender), Some((_, amount))) => proxy_ceiling(game, defender).max(*amount),
+            (None, Some((_, amount))) => *amount,
+            _ => 0,
+        };
+
+        let defender_holds = defender.as_ref().is_some_and(|d| {
+            defender_ceiling > challenger_ceiling
+                || (defender_ceiling == challenger_ceiling && d.timestamp <= challenger.timestamp)
+        });
+
+        let (bidder_id, amount, retired_id) = match defender {
+            Some(defender) if defender_holds => (
+                defender.player_id,
+                (challenger_ceiling + game.bid_increment(challenger_ceiling)).min(defender_ceiling),
+                challenger.player_id,
+            ),
+            defender => {
+                let amount = match &high_bid {
+                    Some(_) => (defender_ceiling + game.bid_increment(defender_ceiling))
+                        .max(minimum)
+                        .min(challenger_ceiling),
+                    None => minimum,
+                };
+                let retired_id = defender.map(|d| d.player_id).unwrap_or_default();
+                (challenger.player_id, amount, retired_id)
+            }
+        };
+
+        game.current_bids.push(Bid {
+            player_id: bidder_id,
+            amount,
+            timestamp: Utc::now(),
+        });
+        game.proxy_bids.retain(|p| p.player_id != retired_id);
+    }
+}
+
+/// What a proxy can actually bid: its ceiling, limited by the player's chips
+fn proxy_ceiling(game: &Game, proxy: &ProxyBid) -> u32 {
+    let chips = game
+        .players
+        .get(&proxy.player_id)
+        .map(|p| p.chips.max(0) as u32)
+        .unwrap_or(0);
+    proxy.max_amount.min(chips)
+}
+
 pub fn resolve_round(game: &mut Game) -> Result<(), String> {
     // Validate game is active
     if !matches!(game.state, GameState::Active { .. }) {
         return Err("Game is not active".to_string());
     }
 
-    // Find highest bid
+    // Find highest bid (earliest bid wins ties)
     let winning_bid = game.get_highest_bid().cloned();
 
+    // Keep every bid so the round can be revealed once it closes
+    let mut revealed_bids = game.current_bids.clone();
+    revealed_bids.sort_by(|a, b| b.amount.cmp(&a.amount).then(a.timestamp.cmp(&b.timestamp)));
+    let round = match game.state {
+        GameState::Active { round, .. } => round,
+        _ => 0,
+    };
+    game.current_price = None;
+    let reserve_price = game.reserve_price();
+
+    // Second-price rounds charge the runner-up's bid (or the configured minimum)
+    let price_paid = match &winning_bid {
+        Some(bid) if game.config.auction_type == AuctionType::SealedSecondPrice => revealed_bids
+            .get(1)
+            .map(|b| b.amount)
+            .unwrap_or(game.config.second_price_minimum)
+            .max(reserve_price)
+            .min(bid.amount),
+        Some(bid) => bid.amount,
+        None => 0,
+    };
+
+    // Unsold Dutch lots may get a second chance once the deck runs out
+    // (unless a seller is around to take their painting back)
+    let seller_id = game.seller_id.clone();
+    let reserved = winning_bid.is_none()
+        && seller_id.is_none()
+        && game.config.auction_type == AuctionType::Dutch
+        && game.config.dutch_floor_rule == FloorRule::Reserve
+        && !game.reserve_dealt;
+
+    game.last_round = Some(RoundResult {
+        round,
+        auction_type: game.config.auction_type,
+        lot: game.current_art.clone(),
+        bids: revealed_bids,
+        winner_id: winning_bid.as_ref().map(|b| b.player_id.clone()),
+        seller_id: seller_id.clone(),
+        price_paid,
+        reserved,
+        reserve_price,
+    });
+
+    // Blind lots are unmasked now, unless only the winner gets to see them
+    if game.config.blind_reveal == BlindReveal::Everyone {
+        for art in &game.current_art {
+            game.hidden_art.remove(&art.id);
+        }
+    }
+
     if let Some(winning_bid) = winning_bid {
+        // Sold lots count towards the season's market rankings
+        let lot = std::mem::take(&mut game.current_art);
+        game.record_sale(&lot);
+
         let player = game
             .players
             .get_mut(&winning_bid.player_id)
             .ok_or("Winner not found")?;
 
         // Deduct chips
-        player.chips -= winning_bid.amount as i32;
+        player.chips -= price_paid as i32;
+
+        // Add the whole lot to the collection
+        player.add_lot(lot, round, price_paid);
 
-        // Add art to collection
-        if let Some(art) = game.current_art.take() {
-            player.collection.push(art);
+        // Player auctioneers keep the proceeds instead of the bank
+        if let Some(seller) = seller_id.and_then(|id| game.players.get_mut(&id)) {
+            seller.chips += price_paid as i32;
         }
+    } else if let Some(seller) = seller_id.and_then(|id| game.players.get_mut(&id)) {
+        // Nobody bid - the seller keeps their painting for free
+        seller.add_lot(std::mem::take(&mut game.current_art), round, 0);
+    } else if reserved {
+        game.reserve_pile.append(&mut game.current_art);
     } else {
-        // No bids - discard art
-        if let Some(art) = game.current_art.take() {
-            game.discard_pile.push(art);
-        }
+        // No bids - discard art, marking lots that had a reserve as unsold
+        let reason = if reserve_price > 0 {
+            DiscardReason::Unsold
+        } else {
+            DiscardReason::NoBids
+        };
+        game.discard_pile.extend(
+            std::mem::take(&mut game.current_art)
+                .into_iter()
+                .map(|art| DiscardedPiece { art, round, reason }),
+        );
     }
 
-    // Clear bids
+    // Clear bids and turn tracking
     game.current_bids.clear();
+    game.proxy_bids.clear();
+    game.passed_players.clear();
+    game.turn_index = 0;
+    game.round_started_at = None;
+
+    // Re-offer the reserve once the regular deck is exhausted
+    if game.deck.is_empty() && !game.reserve_pile.is_empty() {
+        game.deck.append(&mut game.reserve_pile);
+        game.reserve_dealt = true;
+    }
+
+    // Seasons close every few rounds, and always with the final round
+    if game.config.market_enabled
+        && (round % game.config.season_length.max(1) == 0 || !game.has_art_remaining())
+    {
+        end_season(game);
+    }
 
     // Check if game is over
-    if game.deck.is_empty() {
+    if !game.has_art_remaining() {
         finish_game(game, None)?; // next_game_id will be set by timer service
         return Ok(());
     }
 
-    // Deal next art piece and continue
-    game.deal_next_art();
+    // Deal next art piece, or hand over to the next seller to choose one
+    if game.config.game_mode == GameMode::PlayerAuctioneer {
+        game.seller_id = game.next_seller();
+    } else {
+        game.deal_next_art();
+    }
 
     // Move to next round, waiting for host to start
     if let GameState::Active { round, .. } = game.state {
@@ -131,11 +521,208 @@ pub fn resolve_round(game: &mut Game) -> Result<(), String> {
             round: round + 1,
             timer_ends_at: None, // Host will start the next round manually
         };
+        apply_round_income(game);
     }
 
     Ok(())
 }
 
+/// Close the season: its best-selling artists gain market value.
+/// Ties go to the artist who sold first this season.
+fn end_season(game: &mut Game) {
+    let mut sales = std::mem::take(&mut game.season_sales);
+    game.season_blind_sales.clear();
+    sales.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    game.last_season_awards = sales
+        .iter()
+        .zip(MARKET_AWARDS)
+        .map(|((artist, _), award)| (*artist, award))
+        .collect();
+    for (artist, award) in &game.last_season_awards {
+        *game.market_values.entry(*artist).or_default() += award;
+    }
+
+    game.season += 1;
+}
+
+/// Pay interest on unspent chips, then the per-round stipend.
+/// Loan interest comes out of chips and may leave a player in the red.
+fn apply_round_income(game: &mut Game) {
+    let stipend = game.config.round_stipend as i32;
+    let interest_percent = game.config.interest_percent as i32;
+    let loan_interest_percent = game.config.loan_interest_percent;
+
+    for player in game.players.values_mut() {
+        // Borrowed chips don't earn interest, or loans would pay for themselves
+        let own_chips = (player.chips - player.debt as i32).max(0);
+        player.chips += own_chips * interest_percent / 100;
+        player.chips += stipend;
+        player.chips -= (player.debt * loan_interest_percent).div_ceil(100) as i32;
+    }
+}
+
+/// Sell a painting from a player's collection back to the bank
+pub fn sell_to_bank(game: &mut Game, player_id: &str, art_id: usize) -> Result<u32, String> {
+    if !game.config.sell_back_enabled {
+        return Err("Selling to the bank is disabled in this game".to_string());
+    }
+
+    let round = match game.state {
+        GameState::Active { round, .. } => round,
+        _ => return Err("Game is not active".to_string()),
+    };
+
+    let player = game.players.get(player_id).ok_or("Player not found")?;
+    let art = player
+        .collection
+        .iter()
+        .find(|a| a.id == art_id)
+        .ok_or("That painting isn't in your collection")?;
+    let price = game.sell_back_price(player, art);
+
+    let player = game.players.get_mut(player_id).ok_or("Player not found")?;
+    let art = player
+        .take_art(&[art_id])
+        .pop()
+        .ok_or("That painting isn't in your collection")?;
+    player.chips += price as i32;
+
+    // Player auctioneers never deal from the deck, so their sales are always discarded
+    if game.config.sell_back_destination == SellBackDestination::Deck
+        && game.config.game_mode == GameMode::BankAuction
+    {
+        game.deck.insert(0, art);
+    } else {
+        game.discard_pile.push(DiscardedPiece {
+            art,
+            round,
+            reason: DiscardReason::SoldBack,
+        });
+    }
+
+    Ok(price)
+}
+
+/// Pay to learn whether a painting in your collection is genuine
+pub fn authenticate_art(game: &mut Game, player_id: &str, art_id: usize) -> Result<bool, String> {
+    if !game.config.authentication_enabled {
+        return Err("Authentication is disabled in this game".to_string());
+    }
+
+    if !matches!(game.state, GameState::Active { .. }) {
+        return Err("Game is not active".to_string());
+    }
+
+    let cost = game.config.authentication_cost;
+    let genuine = !game.is_forgery(art_id);
+    let player = game.players.get_mut(player_id).ok_or("Player not found")?;
+    if !player.owns_art(art_id) {
+        return Err("That painting isn't in your collection".to_string());
+    }
+    if player.authenticated.contains(&art_id) {
+        return Err("You've already had that painting authenticated".to_string());
+    }
+    if player.chips < cost as i32 {
+        return Err(format!("Authentication costs {} chips", cost));
+    }
+
+    player.chips -= cost as i32;
+    player.authenticated.insert(art_id);
+
+    Ok(genuine)
+}
+
+/// Borrow chips from the bank, up to the configured loan limit
+pub fn take_loan(game: &mut Game, player_id: &str, amount: u32) -> Result<(), String> {
+    if !game.config.loans_enabled {
+        return Err("Loans are disabled in this game".to_string());
+    }
+
+    if !matches!(game.state, GameState::Active { .. }) {
+        return Err("Game is not active".to_string());
+    }
+
+    if amount == 0 {
+        return Err("Invalid loan amount".to_string());
+    }
+
+    let loan_limit = game.config.loan_limit;
+    let player = game.players.get_mut(player_id).ok_or("Player not found")?;
+    if amount > loan_limit.saturating_sub(player.debt) {
+        return Err(format!(
+            "You can only owe the bank {} chips - you already owe {}",
+            loan_limit, player.debt
+        ));
+    }
+
+    player.debt += amount;
+    player.chips += amount as i32;
+
+    Ok(())
+}
+
+/// Hand chips to a teammate
+pub fn transfer_chips(
+    game: &mut Game,
+    player_id: &str,
+    teammate_id: &str,
+    amount: u32,
+) -> Result<(), String> {
+    if !matches!(game.state, GameState::Active { .. }) {
+        return Err("Game is not active".to_string());
+    }
+
+    if !game.are_teammates(player_id, teammate_id) {
+        return Err("You can only send chips to a teammate".to_string());
+    }
+
+    let amount = match i32::try_from(amount) {
+        Ok(amount) if amount > 0 => amount,
+        _ => return Err("Invalid transfer amount".to_string()),
+    };
+
+    let player = game.players.get(player_id).ok_or("Player not found")?;
+    if player.chips < amount {
+        return Err("Not enough chips".to_string());
+    }
+    let teammate = game.players.get(teammate_id).ok_or("Player not found")?;
+    let teammate_chips = teammate
+        .chips
+        .checked_add(amount)
+        .ok_or("Invalid transfer amount")?;
+
+    if let Some(player) = game.players.get_mut(player_id) {
+        player.chips -= amount;
+    }
+    if let Some(teammate) = game.players.get_mut(teammate_id) {
+        teammate.chips = teammate_chips;
+    }
+
+    Ok(())
+}
+
+/// Pay back part or all of a player's debt
+pub fn repay_loan(game: &mut Game, player_id: &str, amount: u32) -> Result<(), String> {
+    if !matches!(game.state, GameState::Active { .. }) {
+        return Err("Game is not active".to_string());
+    }
+
+    let player = game.players.get_mut(player_id).ok_or("Player not found")?;
+    if amount == 0 || amount > player.debt {
+        return Err(format!("You owe the bank {} chips", player.debt));
+    }
+
+    if player.chips < amount as i32 {
+        return Err("Insufficient chips to repay".to_string());
+    }
+
+    player.debt -= amount;
+    player.chips -= amount as i32;
+
+    Ok(())
+}
+
 pub fn start_round(game: &mut Game) -> Result<(), String> {
     // Validate game is active and waiting for start
     if let GameState::Active {
@@ -147,42 +734,408 @@ pub fn start_round(game: &mut Game) -> Result<(), String> {
             return Err("Round already in progress".to_string());
         }
 
-        // Start the timer
-        let timer_duration = game.config.bid_timer_seconds as i64;
+        if game.current_art.is_empty() {
+            return Err("Waiting for the seller to choose a painting".to_string());
+        }
+
+        // Start the timer (Dutch rounds run until the price has bottomed out)
+        let timer_duration = match game.config.auction_type {
+            AuctionType::Dutch => {
+                game.current_price = Some(game.config.dutch_start_price);
+                dutch_round_seconds(game) as i64
+            }
+            AuctionType::OnceAround => game.config.turn_timer_seconds as i64,
+            _ => game
+                .config
+                .bid_timer_seconds
+                .min(game.config.max_round_seconds) as i64,
+        };
+        game.round_started_at = Some(Utc::now());
+        // Starting the round closes the trading window
+        game.trade_offers.clear();
         game.state = GameState::Active {
             round: *round,
             timer_ends_at: Some(Utc::now() + Duration::seconds(timer_duration)),
         };
 
+        if game.config.auction_type == AuctionType::OnceAround {
+            start_turn(game);
+        }
+
         Ok(())
     } else {
         Err("Game is not active".to_string())
     }
 }
 
+/// Soft close: a bid landing in the final seconds of the clock extends it,
+/// but never past the hard maximum round length.
+fn extend_soft_close(game: &mut Game) {
+    let GameState::Active {
+        round,
+        timer_ends_at: Some(ends_at),
+    } = game.state
+    else {
+        return;
+    };
+
+    let now = Utc::now();
+    if ends_at - now > Duration::seconds(game.config.soft_close_window_seconds as i64) {
+        return;
+    }
+
+    let mut extended = ends_at + Duration::seconds(game.config.soft_close_extension_seconds as i64);
+    if let Some(started_at) = game.round_started_at {
+        extended =
+            extended.min(started_at + Duration::seconds(game.config.max_round_seconds as i64));
+    }
+
+    game.state = GameState::Active {
+        round,
+        timer_ends_at: Some(extended.max(ends_at)),
+    };
+}
+
+/// Put a painting from the seller's hand up for auction this round
+pub fn offer_art(game: &mut Game, player_id: &str, art_id: usize) -> Result<(), String> {
+    match &game.state {
+        GameState::Active {
+            timer_ends_at: None,
+            ..
+        } => {}
+        GameState::Active { .. } => return Err("Round already in progress".to_string()),
+        _ => return Err("Game is not active".to_string()),
+    }
+
+    if !game.is_seller(player_id) {
+        return Err("It's not your turn to sell".to_string());
+    }
+
+    if !game.current_art.is_empty() {
+        return Err("You already chose a painting this round".to_string());
+    }
+
+    let player = game.players.get_mut(player_id).ok_or("Player not found")?;
+    let index = player
+        .hand
+        .iter()
+        .position(|a| a.id == art_id)
+        .ok_or("That painting isn't in your hand")?;
+
+    game.current_art = vec![player.hand.remove(index)];
+
+    Ok(())
+}
+
+/// Pass on the current round. In once-around rounds this uses up the player's turn;
+/// in open rounds the player drops out, and the round ends early once every
+/// rival of the high bidder has passed. Sealed and Dutch rounds ignore passes.
+pub fn pass_bid(game: &mut Game, player_id: &str) -> Result<(), String> {
+    if !matches!(
+        game.state,
+        GameState::Active {
+            timer_ends_at: Some(_),
+            ..
+        }
+    ) {
+        return Err("No round in progress".to_string());
+    }
+
+    if !game.players.contains_key(player_id) {
+        return Err("Player not found".to_string());
+    }
+
+    if game.config.auction_type == AuctionType::OnceAround {
+        if game.current_turn_player().map(String::as_str) != Some(player_id) {
+            return Err("It's not your turn".to_string());
+        }
+
+        game.passed_players.insert(player_id.to_string());
+        advance_turn(game);
+    } else if game.config.auction_type == AuctionType::Open {
+        game.passed_players.insert(player_id.to_string());
+        game.proxy_bids.retain(|p| p.player_id != player_id);
+
+        if all_rivals_passed(game) {
+            close_round(game);
+        }
+    } else {
+        return Err("You can't pass in this auction type".to_string());
+    }
+
+    Ok(())
+}
+
+/// True when every solvent player other than the high bidder has passed.
+/// Players who can't afford to outbid the current high bid don't need to pass.
+fn all_rivals_passed(game: &Game) -> bool {
+    let highest_bid = game.get_highest_bid();
+    let min_raise = game.minimum_bid();
+    let high_bidder = highest_bid.map(|b| b.player_id.as_str());
+
+    game.players
+        .values()
+        .filter(|p| {
+            Some(p.id.as_str()) != high_bidder && !game.is_seller(&p.id) && p.can_bid(min_raise)
+        })
+        .all(|p| game.passed_players.contains(&p.id))
+}
+
+/// The current once-around player ran out of time: treat it as a pass
+pub fn expire_turn(game: &mut Game) {
+    if let Some(player_id) = game.current_turn_player().cloned() {
+        game.passed_players.insert(player_id);
+        advance_turn(game);
+    }
+}
+
+fn advance_turn(game: &mut Game) {
+    game.turn_index += 1;
+    start_turn(game);
+}
+
+/// Start the clock for the next player who can afford to raise, passing
+/// automatically for anyone who can't. Closes the round once everyone has acted.
+fn start_turn(game: &mut Game) {
+    let min_raise = game.minimum_bid();
+
+    while let Some(player_id) = game.current_turn_player().cloned() {
+        // Sellers sit out the bidding on their own painting
+        if game.is_seller(&player_id) {
+            game.turn_index += 1;
+            continue;
+        }
+
+        if game
+            .players
+            .get(&player_id)
+            .is_some_and(|p| p.can_bid(min_raise))
+        {
+            if let GameState::Active { round, .. } = game.state {
+                game.state = GameState::Active {
+                    round,
+                    timer_ends_at: Some(
+                        Utc::now() + Duration::seconds(game.config.turn_timer_seconds as i64),
+                    ),
+                };
+            }
+            return;
+        }
+
+        game.passed_players.insert(player_id);
+        game.turn_index += 1;
+    }
+
+    close_round(game);
+}
+
+/// Lower the asking price of a running Dutch round by one step.
+/// Once the price has sat at the floor for a full tick the round closes unsold.
+pub fn tick_dutch_price(game: &mut Game) {
+    if game.config.auction_type != AuctionType::Dutch || !game.current_bids.is_empty() {
+        return;
+    }
+
+    let Some(price) = game.current_price else {
+        return;
+    };
+
+    if price <= game.config.dutch_floor_price {
+        close_round(game);
+    } else {
+        game.current_price = Some(
+            price
+                .saturating_sub(game.config.dutch_price_step)
+                .max(game.config.dutch_floor_price),
+        );
+    }
+}
+
+/// Claim the current Dutch lot at its asking price. The first buyer wins.
+pub fn buy_current_art(game: &mut Game, player_id: &str) -> Result<u32, String> {
+    if game.config.auction_type != AuctionType::Dutch {
+        return Err("Buying is only available in Dutch auctions".to_string());
+    }
+
+    let price = match (&game.state, game.current_price) {
+        (
+            GameState::Active {
+                timer_ends_at: Some(_),
+                ..
+            },
+            Some(price),
+        ) => price,
+        _ => return Err("Waiting for host to start the round".to_string()),
+    };
+
+    if !game.current_bids.is_empty() {
+        return Err("Someone else already bought this piece".to_string());
+    }
+
+    if game.is_seller(player_id) {
+        return Err("You can't buy your own painting".to_string());
+    }
+
+    let player = game.players.get(player_id).ok_or("Player not found")?;
+    if !player.can_bid(price) {
+        return Err(format!("You can't afford the asking price of {}", price));
+    }
+
+    game.current_bids.push(Bid {
+        player_id: player_id.to_string(),
+        amount: price,
+        timestamp: Utc::now(),
+    });
+    close_round(game);
+
+    Ok(price)
+}
+
+/// Seconds for a Dutch price to fall from start to floor, plus one tick at the floor
+fn dutch_round_seconds(game: &Game) -> u32 {
+    let step = game.config.dutch_price_step.max(1);
+    let drop = game
+        .config
+        .dutch_start_price
+        .saturating_sub(game.config.dutch_floor_price);
+    drop.div_ceil(step) + 1
+}
+
+/// End the running round now so it counts as expired and gets resolved
+fn close_round(game: &mut Game) {
+    if let GameState::Active { round, .. } = game.state {
+        game.state = GameState::Active {
+            round,
+            timer_ends_at: Some(Utc::now()),
+        };
+    }
+}
+
+/// A player, or a whole team, competing for first place
+struct Side {
+    name: String,
+    player_ids: Vec<String>,
+    score: u32,
+}
+
+/// Everyone competing for the win, in name order
+fn sides(game: &Game) -> Vec<Side> {
+    if game.config.teams_enabled {
+        game.teams()
+            .into_iter()
+            .map(|team| Side {
+                name: format!("Team {}", team),
+                player_ids: game
+                    .team_members(team)
+                    .iter()
+                    .map(|p| p.id.clone())
+                    .collect(),
+                score: game.team_score(team),
+            })
+            .collect()
+    } else {
+        let mut sides: Vec<Side> = game
+            .players
+            .values()
+            .map(|p| Side {
+                name: p.name.clone(),
+                player_ids: vec![p.id.clone()],
+                score: game.final_score(p) + game.objective_bonus(p),
+            })
+            .collect();
+        sides.sort_by(|a, b| a.name.cmp(&b.name));
+        sides
+    }
+}
+
+/// "Alice and Bob", "Alice, Bob and Carol"
+fn join_names(sides: &[&Side]) -> String {
+    let names: Vec<&str> = sides.iter().map(|s| s.name.as_str()).collect();
+    match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+        Some((last, _)) => last.to_string(),
+        None => String::new(),
+    }
+}
+
 fn finish_game(game: &mut Game, next_game_id: Option<String>) -> Result<(), String> {
-    // Calculate all player scores
+    // Forgeries are revealed before anything is scored
+    expose_forgeries(game);
+
+    // Calculate all player scores, including market value and debt penalties,
+    // plus the secret objectives revealed now
     let mut scores: Vec<(String, u32)> = game
         .players
         .values()
-        .map(|p| (p.id.clone(), p.calculate_score()))
+        .map(|p| (p.id.clone(), game.final_score(p) + game.objective_bonus(p)))
         .collect();
 
-    // Sort by score descending
-    scores.sort_by(|a, b| b.1.cmp(&a.1));
+    // Players compete alone, or as teams sharing a combined score
+    let mut sides = sides(game);
+    sides.sort_by_key(|s| std::cmp::Reverse(s.score));
+
+    // Find every side tied on the top score
+    let max_score = sides.first().map(|s| s.score).unwrap_or(0);
+    let mut leaders: Vec<&Side> = sides.iter().filter(|s| s.score == max_score).collect();
+
+    // Settle a tie for first with the configured tie-breaker
+    let tie_breaker = game.config.tie_breaker;
+    let mut tie_break = None;
+    if leaders.len() > 1 && tie_breaker != TieBreaker::Shared {
+        let tied_names = join_names(&leaders);
+        let key = |side: &Side| {
+            let players: Vec<&Player> =
+                side.player_ids.iter().map(|id| &game.players[id]).collect();
+            tie_breaker.key(&players)
+        };
+        let best = leaders.iter().map(|s| key(s)).max().unwrap_or(0);
+        leaders.retain(|s| key(s) == best);
 
-    // Find all winners (handle ties)
-    let max_score = scores.first().map(|(_, s)| *s).unwrap_or(0);
-    let winner_ids: Vec<String> = scores
+        tie_break = Some(if leaders.len() == 1 {
+            format!(
+                "{} tied on {} points; {} wins on {}",
+                tied_names,
+                max_score,
+                join_names(&leaders),
+                tie_breaker.name().to_lowercase()
+            )
+        } else {
+            format!(
+                "{} tied on {} points and on {}; {} share the win",
+                tied_names,
+                max_score,
+                tie_breaker.name().to_lowercase(),
+                join_names(&leaders)
+            )
+        });
+    }
+
+    let winner_ids: Vec<String> = leaders
         .iter()
-        .filter(|(_, s)| *s == max_score)
-        .map(|(id, _)| id.clone())
+        .flat_map(|s| s.player_ids.iter().cloned())
         .collect();
 
+    // Rank players by their side's score, winners first, then by their own score
+    let side_score = |player_id: &String| {
+        sides
+            .iter()
+            .find(|s| s.player_ids.contains(player_id))
+            .map(|s| s.score)
+            .unwrap_or(0)
+    };
+    scores.sort_by_key(|(id, s)| {
+        (
+            std::cmp::Reverse(side_score(id)),
+            !winner_ids.contains(id),
+            std::cmp::Reverse(*s),
+        )
+    });
+
     game.state = GameState::Finished {
         winner_ids,
         final_scores: scores,
         next_game_id,
+        tie_break,
     };
 
     Ok(())
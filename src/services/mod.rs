@@ -1,3 +1,5 @@
 pub mod art_database;
 pub mod game_engine;
+pub mod objectives;
 pub mod timer;
+pub mod trading;
@@ -0,0 +1,99 @@
+use crate::models::{Movement, Objective, ObjectiveGoal, Player};
+use once_cell::sync::Lazy;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+/// Every secret objective that can be dealt. Add new ones here.
+pub static OBJECTIVES: Lazy<Vec<Objective>> = Lazy::new(|| {
+    vec![
+        Objective {
+            id: 1,
+            description: "Own 2 Baroque pieces",
+            goal: ObjectiveGoal::MovementCount {
+                movement: Movement::Baroque,
+                count: 2,
+            },
+            bonus: 8,
+        },
+        Objective {
+            id: 2,
+            description: "Own 3 Renaissance pieces",
+            goal: ObjectiveGoal::MovementCount {
+                movement: Movement::Renaissance,
+                count: 3,
+            },
+            bonus: 8,
+        },
+        Objective {
+            id: 3,
+            description: "Own 2 Impressionist pieces",
+            goal: ObjectiveGoal::MovementCount {
+                movement: Movement::Impressionism,
+                count: 2,
+            },
+            bonus: 10,
+        },
+        Objective {
+            id: 4,
+            description: "Own a 3-star Renaissance piece",
+            goal: ObjectiveGoal::StarredPiece {
+                movement: Movement::Renaissance,
+                stars: 3,
+            },
+            bonus: 6,
+        },
+        Objective {
+            id: 5,
+            description: "Own a 3-star Post-Impressionist piece",
+            goal: ObjectiveGoal::StarredPiece {
+                movement: Movement::PostImpressionism,
+                stars: 3,
+            },
+            bonus: 6,
+        },
+        Objective {
+            id: 6,
+            description: "Own a 3-star Surrealist piece",
+            goal: ObjectiveGoal::StarredPiece {
+                movement: Movement::Surrealism,
+                stars: 3,
+            },
+            bonus: 10,
+        },
+        Objective {
+            id: 7,
+            description: "Own pieces from 4 different movements",
+            goal: ObjectiveGoal::DistinctMovements(4),
+            bonus: 8,
+        },
+        Objective {
+            id: 8,
+            description: "Own 2 pieces by the same artist",
+            goal: ObjectiveGoal::SameArtist(2),
+            bonus: 6,
+        },
+        Objective {
+            id: 9,
+            description: "Collect 5 or more pieces",
+            goal: ObjectiveGoal::CollectionSize(5),
+            bonus: 8,
+        },
+        Objective {
+            id: 10,
+            description: "End with more than 50 chips",
+            goal: ObjectiveGoal::ChipsAbove(50),
+            bonus: 10,
+        },
+    ]
+});
+
+/// Deal every player a different secret objective
+pub fn deal_objectives<'a>(players: impl Iterator<Item = &'a mut Player>, rng: &mut impl Rng) {
+    let mut objectives = OBJECTIVES.clone();
+    objectives.shuffle(rng);
+
+    // Only repeat objectives once there are more players than objectives
+    for (player, objective) in players.zip(objectives.iter().cycle()) {
+        player.objective = Some(*objective);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::models::GameState;
+use crate::models::{AuctionType, GameState};
 use crate::services::game_engine;
 use crate::state::AppState;
 use std::time::Duration;
@@ -17,22 +17,40 @@ pub async fn start_timer_checker(state: AppState) {
 }
 
 async fn check_expired_timers(state: &AppState) {
-    // First, broadcast timer updates for all active games
-    {
-        let games = state.games.read().await;
-        for (game_id, game) in games.iter() {
-            if let GameState::Active { timer_ends_at, .. } = &game.state {
-                // Skip games waiting for host to start
-                if timer_ends_at.is_none() {
-                    continue;
+    // First, tick Dutch prices and broadcast timer updates for all active games
+    let updates: Vec<(String, &'static str, String)> = {
+        let mut games = state.games.write().await;
+        games
+            .iter_mut()
+            .filter_map(|(game_id, game)| {
+                if let GameState::Active { timer_ends_at, .. } = &game.state {
+                    // Skip games waiting for host to start
+                    if timer_ends_at.is_none() {
+                        return None;
+                    }
+
+                    // Dutch rounds show a falling price instead of a countdown
+                    if game.config.auction_type == AuctionType::Dutch {
+                        game_engine::tick_dutch_price(game);
+                        let ticker_html =
+                            crate::handlers::partials::render_price_ticker_partial(game);
+                        return Some((game_id.clone(), "price-ticker", ticker_html));
+                    }
+
+                    // Broadcast timer update trigger every second
+                    return Some((
+                        game_id.clone(),
+                        "timer-update",
+                        String::from("<!-- trigger -->"),
+                    ));
                 }
+                None
+            })
+            .collect()
+    };
 
-                // Broadcast timer update trigger every second
-                state
-                    .broadcast_sse(game_id, "timer-update", String::from("<!-- trigger -->"))
-                    .await;
-            }
-        }
+    for (game_id, event_type, html) in updates {
+        state.broadcast_sse(&game_id, event_type, html).await;
     }
 
     // Then check for expired timers
@@ -40,15 +58,9 @@ async fn check_expired_timers(state: &AppState) {
         let games = state.games.read().await;
         games
             .iter()
-            .filter_map(|(id, game)| {
-                if let GameState::Active { timer_ends_at, .. } = &game.state {
-                    // Only resolve if timer is running and expired
-                    if timer_ends_at.is_some() && game.calculate_remaining_seconds() <= 0 {
-                        return Some(id.clone());
-                    }
-                }
-                None
-            })
+            // Only resolve if timer is running and expired
+            .filter(|(_, game)| game.is_round_expired())
+            .map(|(id, _)| id.clone())
             .collect()
     };
 
@@ -58,39 +70,38 @@ async fn check_expired_timers(state: &AppState) {
     }
 }
 
-async fn resolve_game_round(state: &AppState, game_id: &str) {
+/// Resolve an expired round and broadcast the outcome. Rounds that are
+/// still running (or were already resolved) are left alone.
+pub async fn resolve_game_round(state: &AppState, game_id: &str) {
     // First, resolve the round and check if game finished
-    let (is_finished, host_id, winner_ids, final_scores) = {
+    let (is_finished, host_id) = {
         let mut games = state.games.write().await;
 
         if let Some(game) = games.get_mut(game_id) {
+            if !game.is_round_expired() {
+                return;
+            }
+
+            // An expired once-around clock only ends the current player's turn
+            if game.current_turn_player().is_some() {
+                game_engine::expire_turn(game);
+
+                if !game.is_round_expired() {
+                    drop(games);
+                    broadcast_turn_update(state, game_id).await;
+                    return;
+                }
+            }
+
             // Resolve the round
             if let Err(e) = game_engine::resolve_round(game) {
                 tracing::error!("Failed to resolve round for game {}: {}", game_id, e);
                 return;
             }
 
-            // Check if game finished and extract needed data
+            // Check if game finished
             let is_finished = matches!(game.state, GameState::Finished { .. });
-            if is_finished {
-                if let GameState::Finished {
-                    winner_ids,
-                    final_scores,
-                    ..
-                } = &game.state
-                {
-                    (
-                        true,
-                        game.host_id.clone(),
-                        winner_ids.clone(),
-                        final_scores.clone(),
-                    )
-                } else {
-                    (false, String::new(), Vec::new(), Vec::new())
-                }
-            } else {
-                (false, String::new(), Vec::new(), Vec::new())
-            }
+            (is_finished, game.host_id.clone())
         } else {
             return;
         }
@@ -108,12 +119,10 @@ async fn resolve_game_round(state: &AppState, game_id: &str) {
         games.insert(new_game_id.clone(), new_game);
 
         // Update finished game with next_game_id
-        if let Some(game) = games.get_mut(game_id) {
-            game.state = GameState::Finished {
-                winner_ids,
-                final_scores,
-                next_game_id: Some(new_game_id),
-            };
+        if let Some(game) = games.get_mut(game_id)
+            && let GameState::Finished { next_game_id, .. } = &mut game.state
+        {
+            *next_game_id = Some(new_game_id);
         }
     }
 
@@ -125,6 +134,9 @@ async fn resolve_game_round(state: &AppState, game_id: &str) {
         // Broadcast round resolved (new art piece, updated collections)
         let art_html = crate::handlers::partials::render_current_art_partial(game);
         let bid_html = crate::handlers::partials::render_bid_partial(game);
+        let reveal_html = crate::handlers::partials::render_round_reveal_partial(game);
+        let ticker_html = crate::handlers::partials::render_price_ticker_partial(game);
+        let market_html = crate::handlers::partials::render_market_board_partial(game);
 
         drop(games);
 
@@ -132,6 +144,15 @@ async fn resolve_game_round(state: &AppState, game_id: &str) {
             .broadcast_sse(game_id, "round-resolved", art_html)
             .await;
         state.broadcast_sse(game_id, "bid-placed", bid_html).await;
+        state
+            .broadcast_sse(game_id, "round-revealed", reveal_html)
+            .await;
+        state
+            .broadcast_sse(game_id, "price-ticker", ticker_html)
+            .await;
+        state
+            .broadcast_sse(game_id, "market-updated", market_html)
+            .await;
         state
             .broadcast_sse(game_id, "timer-update", String::from("<!-- trigger -->"))
             .await;
@@ -154,3 +175,22 @@ async fn resolve_game_round(state: &AppState, game_id: &str) {
         tracing::info!("Resolved round for game: {}", game_id);
     }
 }
+
+/// Let every client know the turn or bidding situation changed mid-round
+pub async fn broadcast_turn_update(state: &AppState, game_id: &str) {
+    let bid_html = {
+        let games = state.games.read().await;
+        match games.get(game_id) {
+            Some(game) => crate::handlers::partials::render_bid_partial(game),
+            None => return,
+        }
+    };
+
+    state.broadcast_sse(game_id, "bid-placed", bid_html).await;
+    state
+        .broadcast_sse(game_id, "timer-update", String::from("<!-- trigger -->"))
+        .await;
+    state
+        .broadcast_sse(game_id, "bidding-updated", String::from("<!-- trigger -->"))
+        .await;
+}
@@ -0,0 +1,168 @@
+use crate::models::{Game, TradeOffer};
+
+/// Propose a swap of paintings and chips to another player
+pub fn propose_trade(
+    game: &mut Game,
+    from_id: &str,
+    to_id: &str,
+    mut offered_art: Vec<usize>,
+    mut requested_art: Vec<usize>,
+    offered_chips: u32,
+    requested_chips: u32,
+) -> Result<usize, String> {
+    if !game.is_trading_open() {
+        return Err("Trading is only open between rounds".to_string());
+    }
+
+    if from_id == to_id {
+        return Err("You can't trade with yourself".to_string());
+    }
+
+    if offered_art.is_empty() && requested_art.is_empty() {
+        return Err("A trade needs at least one painting".to_string());
+    }
+
+    offered_art.sort_unstable();
+    offered_art.dedup();
+    requested_art.sort_unstable();
+    requested_art.dedup();
+
+    let trade = TradeOffer {
+        id: game.next_trade_id,
+        from_id: from_id.to_string(),
+        to_id: to_id.to_string(),
+        offered_art,
+        requested_art,
+        offered_chips,
+        requested_chips,
+    };
+    validate_trade(game, &trade)?;
+
+    game.next_trade_id += 1;
+    game.trade_offers.push(trade);
+
+    Ok(game.next_trade_id - 1)
+}
+
+/// Accept an offer made to this player, swapping everything in it
+pub fn accept_trade(
+    game: &mut Game,
+    player_id: &str,
+    trade_id: usize,
+) -> Result<TradeOffer, String> {
+    if !game.is_trading_open() {
+        return Err("Trading is only open between rounds".to_string());
+    }
+
+    let index = game
+        .trade_offers
+        .iter()
+        .position(|t| t.id == trade_id && t.to_id == player_id)
+        .ok_or("Trade offer not found")?;
+
+    // Either side may have traded the pieces away (or spent the chips) since
+    if let Err(e) = validate_trade(game, &game.trade_offers[index]) {
+        game.trade_offers.remove(index);
+        return Err(e);
+    }
+    let trade = game.trade_offers.remove(index);
+
+    // Both amounts were validated to fit, so the net flow can't overflow
+    let net_to_from = chip_amount(trade.requested_chips)? - chip_amount(trade.offered_chips)?;
+    let from_chips = game.players[&trade.from_id]
+        .chips
+        .checked_add(net_to_from)
+        .ok_or("Invalid chip amount")?;
+    let to_chips = game.players[&trade.to_id]
+        .chips
+        .checked_sub(net_to_from)
+        .ok_or("Invalid chip amount")?;
+
+    let from = game
+        .players
+        .get_mut(&trade.from_id)
+        .ok_or("Player not found")?;
+    let offered = from.take_art(&trade.offered_art);
+    from.chips = from_chips;
+
+    let to = game
+        .players
+        .get_mut(&trade.to_id)
+        .ok_or("Player not found")?;
+    let requested = to.take_art(&trade.requested_art);
+    to.chips = to_chips;
+    to.collection.extend(offered);
+
+    let from = game
+        .players
+        .get_mut(&trade.from_id)
+        .ok_or("Player not found")?;
+    from.collection.extend(requested);
+
+    // Offers involving the pieces that just moved are no longer valid
+    let moved: Vec<usize> = trade
+        .offered_art
+        .iter()
+        .chain(&trade.requested_art)
+        .copied()
+        .collect();
+    game.trade_offers.retain(|t| {
+        !t.offered_art
+            .iter()
+            .chain(&t.requested_art)
+            .any(|art_id| moved.contains(art_id))
+    });
+
+    Ok(trade)
+}
+
+/// Turn down an offer, or withdraw one you made
+pub fn reject_trade(game: &mut Game, player_id: &str, trade_id: usize) -> Result<(), String> {
+    let index = game
+        .trade_offers
+        .iter()
+        .position(|t| t.id == trade_id && (t.to_id == player_id || t.from_id == player_id))
+        .ok_or("Trade offer not found")?;
+
+    game.trade_offers.remove(index);
+
+    Ok(())
+}
+
+/// Both sides must still own the paintings and chips they are putting up
+fn validate_trade(game: &Game, trade: &TradeOffer) -> Result<(), String> {
+    let from = game.players.get(&trade.from_id).ok_or("Player not found")?;
+    let to = game
+        .players
+        .get(&trade.to_id)
+        .ok_or("Trading partner not found")?;
+
+    if !trade.offered_art.iter().all(|&id| from.owns_art(id)) {
+        return Err(format!(
+            "{} no longer owns every painting offered",
+            from.name
+        ));
+    }
+
+    if !trade.requested_art.iter().all(|&id| to.owns_art(id)) {
+        return Err(format!(
+            "{} no longer owns every painting requested",
+            to.name
+        ));
+    }
+
+    if from.chips < chip_amount(trade.offered_chips)? {
+        return Err(format!("{} can't cover the chips offered", from.name));
+    }
+
+    if to.chips < chip_amount(trade.requested_chips)? {
+        return Err(format!("{} can't cover the chips requested", to.name));
+    }
+
+    Ok(())
+}
+
+/// A chip amount from a form, as a balance change
+fn chip_amount(chips: u32) -> Result<i32, String> {
+    i32::try_from(chips).map_err(|_| "Invalid chip amount".to_string())
+}
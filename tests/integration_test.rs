@@ -1,6 +1,16 @@
 mod common;
 
+use chrono::{Duration, Utc};
+use collector::handlers::partials;
+use collector::models::{
+    Artist, AuctionType, BlindHides, BlindReveal, ChosenBonus, DeckStrategy, DiscardReason,
+    FloorRule, Game, GameConfig, GameMode, GameState, IncrementRule, Movement, ObjectiveGoal,
+    Player, ScoringMode, SellBackBasis, SellBackDestination, TeamScoring, TieBreaker,
+};
+use collector::services::{art_database, game_engine, objectives, trading};
 use common::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 
 // Helper function to create and join a game, returning game_id and player_id
 async fn setup_joined_game(server: &axum_test::TestServer, player_name: &str) -> (String, String) {
@@ -21,6 +31,27 @@ async fn setup_joined_game(server: &axum_test::TestServer, player_name: &str) ->
     (game_id, player_id)
 }
 
+// Helper function to start a game directly through the engine, returning the game and player ids
+fn setup_engine_game(player_names: &[&str], config: GameConfig) -> (Game, Vec<String>) {
+    let mut game = Game::new("engine-game".to_string(), "p0".to_string());
+    game.config = config;
+
+    let mut player_ids = Vec::new();
+    for (i, name) in player_names.iter().enumerate() {
+        let player_id = format!("p{}", i);
+        let player = Player::new(
+            player_id.clone(),
+            name.to_string(),
+            game.config.starting_chips,
+        );
+        game.add_player(player).unwrap();
+        player_ids.push(player_id);
+    }
+
+    game_engine::start_game(&mut game).unwrap();
+    (game, player_ids)
+}
+
 #[tokio::test]
 async fn test_create_game() {
     let server = create_test_server();
@@ -431,6 +462,30 @@ async fn test_lobby_view_endpoint() {
     assert!(body.contains("Lobby") || body.contains("lobby") || body.contains("Start"));
 }
 
+#[test]
+fn test_player_lobby_settings_show_every_rule() {
+    let mut game = Game::new("game".to_string(), "host".to_string());
+    game.config = GameConfig {
+        loans_enabled: true,
+        loan_limit: 80,
+        market_enabled: true,
+        season_length: 3,
+        ..GameConfig::default()
+    };
+
+    let html = partials::render_lobby_settings_player(&game);
+    for expected in [
+        "Up to 80 chips",
+        "Trading",
+        "3-round seasons",
+        "Secret Objectives",
+        "60 → 4 chips",
+        "+10s",
+    ] {
+        assert!(html.contains(expected), "missing {}", expected);
+    }
+}
+
 #[tokio::test]
 async fn test_home_page() {
     let server = create_test_server();
@@ -466,6 +521,7 @@ async fn test_complete_game_flow() {
             ("starting_chips", "100"),
             ("bid_timer_seconds", "30"),
             ("num_rounds", "2"),
+            ("seed", "42"),
         ])
         .await;
     assert!(config_response.status_code().is_success());
@@ -499,4 +555,1312 @@ async fn test_complete_game_flow() {
         .get(&format!("/game/{}/play?player_id={}", game_id, host_id))
         .await;
     assert_eq!(game_view.status_code(), 200);
+
+    // 8. The seeded deck puts the same painting up first every time
+    let (seeded, _) = setup_engine_game(
+        &["Host", "Alice"],
+        GameConfig {
+            num_rounds: 2,
+            seed: Some(42),
+            ..GameConfig::default()
+        },
+    );
+    assert!(game_view.text().contains(seeded.current_art[0].name));
+}
+
+#[test]
+fn test_sealed_bid_round() {
+    let config = GameConfig {
+        auction_type: AuctionType::SealedFirstPrice,
+        num_rounds: 2,
+        ..GameConfig::default()
+    };
+    let (mut game, ids) = setup_engine_game(&["Alice", "Bob", "Carol"], config);
+    game_engine::start_round(&mut game).unwrap();
+
+    // Sealed bids don't need to beat each other, but each player only gets one
+    game_engine::place_bid(&mut game, &ids[0], 20).unwrap();
+    game_engine::place_bid(&mut game, &ids[1], 5).unwrap();
+    game_engine::place_bid(&mut game, &ids[2], 20).unwrap();
+    assert!(game_engine::place_bid(&mut game, &ids[1], 30).is_err());
+
+    game_engine::resolve_round(&mut game).unwrap();
+
+    // Tie at 20 goes to the earliest bid, and every bid is revealed
+    let result = game.last_round.as_ref().unwrap();
+    assert_eq!(result.winner_id.as_deref(), Some(ids[0].as_str()));
+    assert_eq!(result.price_paid, 20);
+    assert_eq!(result.bids.len(), 3);
+    assert_eq!(game.players[&ids[0]].chips, 80);
+    assert_eq!(game.players[&ids[0]].collection.len(), 1);
+    assert_eq!(game.players[&ids[2]].chips, 100);
+    assert!(matches!(
+        game.state,
+        GameState::Active {
+            round: 2,
+            timer_ends_at: None
+        }
+    ));
+}
+
+#[tokio::test]
+async fn test_sealed_bid_is_hidden() {
+    let server = create_test_server();
+    let (game_id, host_id) = setup_joined_game(&server, "Host").await;
+    server
+        .post(&format!("/join/{}", game_id))
+        .form(&[("player_name", "Alice")])
+        .await;
+
+    server
+        .post(&format!("/lobby/{}/configure", game_id))
+        .form(&[
+            ("starting_chips", "100"),
+            ("bid_timer_seconds", "30"),
+            ("num_rounds", "2"),
+            ("auction_type", "sealed_first_price"),
+        ])
+        .await;
+    server
+        .post(&format!("/lobby/{}/start?player_id={}", game_id, host_id))
+        .await;
+    server
+        .post(&format!("/game/{}/start-round", game_id))
+        .form(&[("player_id", host_id.as_str())])
+        .await;
+
+    let bid_response = server
+        .post(&format!("/game/{}/bid", game_id))
+        .form(&[("player_id", host_id.as_str()), ("amount", "37")])
+        .await;
+    assert!(bid_response.status_code().is_success());
+
+    // The game page must not show the sealed amount
+    let game_view = server
+        .get(&format!("/game/{}/play?player_id={}", game_id, host_id))
+        .await;
+    assert_eq!(game_view.status_code(), 200);
+    assert!(game_view.text().contains("Sealed bidding"));
+    assert!(!game_view.text().contains("37 chips"));
+
+    // Only the bidder sees their own bid
+    let bidding_area = server
+        .get(&format!(
+            "/game/{}/bidding-area?player_id={}",
+            game_id, host_id
+        ))
+        .await;
+    assert!(bidding_area.text().contains("37 chips"));
+}
+
+#[tokio::test]
+async fn test_dutch_floor_price_is_at_least_one() {
+    let server = create_test_server();
+    let (game_id, host_id) = setup_joined_game(&server, "Host").await;
+
+    server
+        .post(&format!("/lobby/{}/configure", game_id))
+        .form(&[
+            ("starting_chips", "100"),
+            ("bid_timer_seconds", "30"),
+            ("num_rounds", "2"),
+            ("auction_type", "dutch"),
+            ("dutch_floor_price", "0"),
+        ])
+        .await;
+
+    let lobby = server
+        .get(&format!("/lobby/{}?player_id={}", game_id, host_id))
+        .await;
+    assert!(
+        lobby
+            .text()
+            .contains(r#"name="dutch_floor_price" min="1" max="500" value="1""#)
+    );
+}
+
+#[test]
+fn test_second_price_round() {
+    let config = GameConfig {
+        auction_type: AuctionType::SealedSecondPrice,
+        second_price_minimum: 5,
+        num_rounds: 3,
+        ..GameConfig::default()
+    };
+    let (mut game, ids) = setup_engine_game(&["Alice", "Bob", "Carol"], config);
+
+    // Highest bidder wins but pays the second-highest bid
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::place_bid(&mut game, &ids[0], 10).unwrap();
+    game_engine::place_bid(&mut game, &ids[1], 40).unwrap();
+    game_engine::place_bid(&mut game, &ids[2], 25).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    let result = game.last_round.as_ref().unwrap();
+    assert_eq!(result.winner_id.as_deref(), Some(ids[1].as_str()));
+    assert_eq!(result.bids[0].amount, 40);
+    assert_eq!(result.price_paid, 25);
+    assert_eq!(game.players[&ids[1]].chips, 75);
+
+    // A lone bidder pays the configured minimum
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::place_bid(&mut game, &ids[0], 30).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    assert_eq!(game.last_round.as_ref().unwrap().price_paid, 5);
+    assert_eq!(game.players[&ids[0]].chips, 95);
+}
+
+#[test]
+fn test_dutch_price_falls_to_floor_and_reserves() {
+    let config = GameConfig {
+        auction_type: AuctionType::Dutch,
+        dutch_start_price: 10,
+        dutch_price_step: 4,
+        dutch_floor_price: 5,
+        dutch_floor_rule: FloorRule::Reserve,
+        num_rounds: 1,
+        ..GameConfig::default()
+    };
+    let (mut game, ids) = setup_engine_game(&["Alice", "Bob"], config);
+    let art_id = game.current_art[0].id;
+
+    game_engine::start_round(&mut game).unwrap();
+    assert_eq!(game.current_price, Some(10));
+    assert!(game_engine::place_bid(&mut game, &ids[0], 10).is_err());
+
+    // Price never drops below the floor, and sitting there for a tick closes the round
+    game_engine::tick_dutch_price(&mut game);
+    assert_eq!(game.current_price, Some(6));
+    game_engine::tick_dutch_price(&mut game);
+    assert_eq!(game.current_price, Some(5));
+    assert!(!game.is_round_expired());
+    game_engine::tick_dutch_price(&mut game);
+    assert!(game.is_round_expired());
+
+    // Unsold piece comes back once from the reserve...
+    game_engine::resolve_round(&mut game).unwrap();
+    assert!(game.last_round.as_ref().unwrap().reserved);
+    assert_eq!(game.current_art[0].id, art_id);
+    assert!(matches!(game.state, GameState::Active { round: 2, .. }));
+
+    // ...and is discarded if it fails again
+    game_engine::start_round(&mut game).unwrap();
+    for _ in 0..3 {
+        game_engine::tick_dutch_price(&mut game);
+    }
+    game_engine::resolve_round(&mut game).unwrap();
+    assert_eq!(game.discard_pile.len(), 1);
+    assert!(matches!(game.state, GameState::Finished { .. }));
+}
+
+#[tokio::test]
+async fn test_dutch_buy_resolves_round() {
+    let server = create_test_server();
+    let (game_id, host_id) = setup_joined_game(&server, "Host").await;
+    server
+        .post(&format!("/join/{}", game_id))
+        .form(&[("player_name", "Alice")])
+        .await;
+
+    server
+        .post(&format!("/lobby/{}/configure", game_id))
+        .form(&[
+            ("starting_chips", "100"),
+            ("bid_timer_seconds", "30"),
+            ("num_rounds", "2"),
+            ("auction_type", "dutch"),
+            ("dutch_start_price", "60"),
+            ("dutch_price_step", "2"),
+            ("dutch_floor_price", "4"),
+            ("dutch_floor_rule", "discard"),
+        ])
+        .await;
+    server
+        .post(&format!("/lobby/{}/start?player_id={}", game_id, host_id))
+        .await;
+    server
+        .post(&format!("/game/{}/start-round", game_id))
+        .form(&[("player_id", host_id.as_str())])
+        .await;
+
+    let buy_response = server
+        .post(&format!("/game/{}/buy", game_id))
+        .form(&[("player_id", host_id.as_str())])
+        .await;
+    assert!(buy_response.status_code().is_success());
+
+    // The buyer paid the starting price and the next round is waiting for the host
+    let player_info = server
+        .get(&format!(
+            "/game/{}/player-info?player_id={}",
+            game_id, host_id
+        ))
+        .await;
+    assert!(player_info.text().contains(">40<"));
+    assert!(player_info.text().contains("Round 2"));
+}
+
+#[test]
+fn test_once_around_turn_order() {
+    let config = GameConfig {
+        auction_type: AuctionType::OnceAround,
+        num_rounds: 2,
+        ..GameConfig::default()
+    };
+    let (mut game, _) = setup_engine_game(&["Alice", "Bob", "Carol"], config);
+    let seats = game.turn_order.clone();
+    game_engine::start_round(&mut game).unwrap();
+
+    // Only the player whose turn it is may act
+    assert_eq!(game.current_turn_player(), Some(&seats[0]));
+    assert!(game_engine::place_bid(&mut game, &seats[1], 10).is_err());
+    assert!(game_engine::pass_bid(&mut game, &seats[1]).is_err());
+
+    game_engine::place_bid(&mut game, &seats[0], 10).unwrap();
+    game_engine::pass_bid(&mut game, &seats[1]).unwrap();
+    assert!(game.passed_players.contains(&seats[1]));
+    assert!(!game.is_round_expired());
+
+    // Running out the last player's clock counts as a pass and ends the round
+    game_engine::expire_turn(&mut game);
+    assert!(game.is_round_expired());
+    game_engine::resolve_round(&mut game).unwrap();
+    assert_eq!(
+        game.last_round.as_ref().unwrap().winner_id.as_ref(),
+        Some(&seats[0])
+    );
+
+    // The starting seat rotates each round
+    game_engine::start_round(&mut game).unwrap();
+    assert_eq!(game.current_turn_player(), Some(&seats[1]));
+}
+
+#[test]
+fn test_open_round_ends_when_rivals_pass() {
+    let config = GameConfig {
+        num_rounds: 2,
+        ..GameConfig::default()
+    };
+    let (mut game, ids) = setup_engine_game(&["Alice", "Bob", "Carol", "Dave"], config);
+    game_engine::start_round(&mut game).unwrap();
+
+    // Dave is broke, so he doesn't need to pass
+    game.players.get_mut(&ids[3]).unwrap().chips = 0;
+
+    game_engine::place_bid(&mut game, &ids[0], 10).unwrap();
+    game_engine::pass_bid(&mut game, &ids[1]).unwrap();
+    assert!(game_engine::place_bid(&mut game, &ids[1], 20).is_err());
+    assert!(!game.is_round_expired());
+
+    game_engine::pass_bid(&mut game, &ids[2]).unwrap();
+    assert!(game.is_round_expired());
+
+    game_engine::resolve_round(&mut game).unwrap();
+    assert_eq!(game.players[&ids[0]].collection.len(), 1);
+    assert!(game.passed_players.is_empty());
+}
+
+#[test]
+fn test_sealed_and_dutch_rounds_reject_passes() {
+    for auction_type in [
+        AuctionType::SealedFirstPrice,
+        AuctionType::SealedSecondPrice,
+        AuctionType::Dutch,
+    ] {
+        let (mut game, ids) = setup_engine_game(
+            &["Alice", "Bob"],
+            GameConfig {
+                auction_type,
+                ..GameConfig::default()
+            },
+        );
+        game_engine::start_round(&mut game).unwrap();
+
+        assert!(game_engine::pass_bid(&mut game, &ids[0]).is_err());
+        assert!(game.passed_players.is_empty());
+        assert!(!partials::render_bidding_area_partial(&game, &ids[0], &game.id).contains("/pass"));
+    }
+}
+
+#[tokio::test]
+async fn test_pass_resolves_round_early() {
+    let server = create_test_server();
+    let (game_id, host_id) = setup_joined_game(&server, "Host").await;
+    let join_response = server
+        .post(&format!("/join/{}", game_id))
+        .form(&[("player_name", "Alice")])
+        .await;
+    let alice_location = extract_location(join_response.headers()).unwrap();
+    let alice_id = extract_player_id_from_url(&alice_location).unwrap();
+
+    server
+        .post(&format!("/lobby/{}/start?player_id={}", game_id, host_id))
+        .await;
+    server
+        .post(&format!("/game/{}/start-round", game_id))
+        .form(&[("player_id", host_id.as_str())])
+        .await;
+    server
+        .post(&format!("/game/{}/bid", game_id))
+        .form(&[("player_id", host_id.as_str()), ("amount", "15")])
+        .await;
+
+    let pass_response = server
+        .post(&format!("/game/{}/pass", game_id))
+        .form(&[("player_id", alice_id.as_str())])
+        .await;
+    assert!(pass_response.status_code().is_success());
+
+    // The round resolved right away instead of waiting for the timer
+    let player_info = server
+        .get(&format!(
+            "/game/{}/player-info?player_id={}",
+            game_id, host_id
+        ))
+        .await;
+    assert!(player_info.text().contains(">85<"));
+    assert!(player_info.text().contains("Round 2"));
+}
+
+#[test]
+fn test_player_auctioneer_proceeds_go_to_seller() {
+    let config = GameConfig {
+        game_mode: GameMode::PlayerAuctioneer,
+        num_rounds: 4,
+        ..GameConfig::default()
+    };
+    let (mut game, _) = setup_engine_game(&["Alice", "Bob"], config);
+    let seats = game.turn_order.clone();
+
+    // Paintings are dealt into hands and the first seller has to choose
+    assert!(game.deck.is_empty() && game.current_art.is_empty());
+    assert_eq!(game.players[&seats[0]].hand.len(), 2);
+    assert_eq!(game.seller_id.as_ref(), Some(&seats[0]));
+    assert!(game_engine::start_round(&mut game).is_err());
+
+    let art_id = game.players[&seats[0]].hand[0].id;
+    assert!(game_engine::offer_art(&mut game, &seats[1], art_id).is_err());
+    game_engine::offer_art(&mut game, &seats[0], art_id).unwrap();
+    game_engine::start_round(&mut game).unwrap();
+
+    // The seller can't bid; the winning bid goes to them
+    assert!(game_engine::place_bid(&mut game, &seats[0], 5).is_err());
+    game_engine::place_bid(&mut game, &seats[1], 20).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+    assert_eq!(game.players[&seats[0]].chips, 120);
+    assert_eq!(game.players[&seats[1]].chips, 80);
+    assert_eq!(game.players[&seats[1]].collection[0].id, art_id);
+
+    // Seller rotates; with no bids they keep their painting for free
+    assert_eq!(game.seller_id.as_ref(), Some(&seats[1]));
+    let art_id = game.players[&seats[1]].hand[0].id;
+    game_engine::offer_art(&mut game, &seats[1], art_id).unwrap();
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+    assert_eq!(game.players[&seats[1]].chips, 80);
+    assert_eq!(game.players[&seats[1]].collection.len(), 2);
+}
+
+#[test]
+fn test_multi_piece_lot_goes_to_winner() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            num_rounds: 2,
+            lot_size: 3,
+            ..GameConfig::default()
+        },
+    );
+
+    // The deck is sized for every lot, and a whole lot is up each round
+    assert_eq!(game.current_art.len(), 3);
+    assert_eq!(game.deck.len(), 3);
+    let lot_ids: Vec<usize> = game.current_art.iter().map(|a| a.id).collect();
+
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::place_bid(&mut game, &ids[1], 30).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    // One payment buys every piece in the lot
+    let bob = &game.players[&ids[1]];
+    assert_eq!(bob.chips, 70);
+    assert_eq!(bob.collection.len(), 3);
+    for art_id in lot_ids {
+        let acquisition = &bob.acquisitions[&art_id];
+        assert_eq!(acquisition.round, 1);
+        assert_eq!(acquisition.lot_size, 3);
+        assert_eq!(acquisition.price, 30);
+    }
+    assert_eq!(game.last_round.as_ref().unwrap().lot.len(), 3);
+    assert_eq!(game.current_art.len(), 3);
+    assert!(game.deck.is_empty());
+}
+
+#[test]
+fn test_soft_close_extends_late_bids_only() {
+    let (mut game, ids) = setup_engine_game(&["Alice", "Bob"], GameConfig::default());
+    game_engine::start_round(&mut game).unwrap();
+
+    let timer_ends_at = |game: &Game| match game.state {
+        GameState::Active {
+            timer_ends_at: Some(ends_at),
+            ..
+        } => ends_at,
+        _ => panic!("round should be running"),
+    };
+
+    // An early bid leaves the clock alone
+    let ends_at = timer_ends_at(&game);
+    game_engine::place_bid(&mut game, &ids[0], 10).unwrap();
+    assert_eq!(timer_ends_at(&game), ends_at);
+
+    // A bid inside the closing window extends it
+    let ends_at = Utc::now() + Duration::seconds(5);
+    game.state = GameState::Active {
+        round: 1,
+        timer_ends_at: Some(ends_at),
+    };
+    game_engine::place_bid(&mut game, &ids[1], 15).unwrap();
+    assert_eq!(timer_ends_at(&game), ends_at + Duration::seconds(10));
+
+    // ...but never past the hard maximum round length
+    let started_at = Utc::now() - Duration::seconds(115);
+    game.round_started_at = Some(started_at);
+    game.state = GameState::Active {
+        round: 1,
+        timer_ends_at: Some(ends_at),
+    };
+    game_engine::place_bid(&mut game, &ids[0], 20).unwrap();
+    assert_eq!(timer_ends_at(&game), started_at + Duration::seconds(120));
+}
+
+#[test]
+fn test_reserve_price_and_min_increment() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            reserve_per_star: 5,
+            min_increment: 10,
+            increment_rule: IncrementRule::Percent,
+            ..GameConfig::default()
+        },
+    );
+    game_engine::start_round(&mut game).unwrap();
+
+    // Bids have to meet the reserve...
+    let reserve = game.current_art[0].stars as u32 * 5;
+    assert_eq!(game.reserve_price(), reserve);
+    assert!(game_engine::place_bid(&mut game, &ids[0], reserve - 1).is_err());
+    game_engine::place_bid(&mut game, &ids[0], 50).unwrap();
+
+    // ...and raise by at least 10% of the high bid
+    assert_eq!(game.minimum_bid(), 55);
+    assert!(game_engine::place_bid(&mut game, &ids[1], 54).is_err());
+    game_engine::place_bid(&mut game, &ids[1], 55).unwrap();
+
+    // Bids too big for a chip balance are refused, and huge increments don't overflow
+    assert!(game_engine::place_bid(&mut game, &ids[0], u32::MAX).is_err());
+    assert!(!game.players[&ids[0]].can_bid(1 << 31));
+    assert_eq!(game.bid_increment(u32::MAX), u32::MAX.div_ceil(100));
+}
+
+#[test]
+fn test_lot_below_reserve_is_discarded_as_unsold() {
+    let (mut game, _ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            reserve_per_star: 20,
+            ..GameConfig::default()
+        },
+    );
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    assert_eq!(game.discard_pile.len(), 1);
+    assert_eq!(game.discard_pile[0].reason, DiscardReason::Unsold);
+    assert!(game.last_round.as_ref().unwrap().reserve_price > 0);
+}
+
+#[test]
+fn test_round_income_pays_interest_then_stipend() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            round_stipend: 5,
+            interest_percent: 10,
+            ..GameConfig::default()
+        },
+    );
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::place_bid(&mut game, &ids[0], 50).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    // 50 left + 5 interest + 5 stipend; 100 + 10 interest + 5 stipend
+    assert_eq!(game.players[&ids[0]].chips, 60);
+    assert_eq!(game.players[&ids[1]].chips, 115);
+}
+
+#[test]
+fn test_borrowed_chips_earn_no_interest() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            interest_percent: 20,
+            loans_enabled: true,
+            loan_limit: 50,
+            loan_interest_percent: 5,
+            ..GameConfig::default()
+        },
+    );
+    game_engine::take_loan(&mut game, &ids[0], 50).unwrap();
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    // 150 chips, 20% interest on the 100 she owns, less 3 chips of loan interest
+    assert_eq!(game.players[&ids[0]].chips, 167);
+    assert_eq!(game.players[&ids[1]].chips, 120);
+}
+
+#[test]
+fn test_loans_charge_interest_and_penalise_unpaid_debt() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            num_rounds: 2,
+            loans_enabled: true,
+            loan_limit: 50,
+            loan_interest_percent: 10,
+            ..GameConfig::default()
+        },
+    );
+
+    game_engine::take_loan(&mut game, &ids[0], 40).unwrap();
+    assert!(game_engine::take_loan(&mut game, &ids[0], 20).is_err());
+    assert!(game_engine::take_loan(&mut game, &ids[0], u32::MAX).is_err());
+    game_engine::repay_loan(&mut game, &ids[0], 10).unwrap();
+    assert_eq!(game.players[&ids[0]].chips, 130);
+    assert_eq!(game.players[&ids[0]].debt, 30);
+
+    // Alice spends everything; 10% interest on her debt pushes her into the red
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::place_bid(&mut game, &ids[0], 130).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+    assert_eq!(game.players[&ids[0]].chips, -3);
+
+    // 33 chips of unpaid debt cost 7 points at the end
+    let alice_score = game.players[&ids[0]].calculate_score(game.config.scoring);
+    assert_eq!(game.players[&ids[0]].debt_penalty(), 7);
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+    match &game.state {
+        GameState::Finished { final_scores, .. } => {
+            let (_, score) = final_scores.iter().find(|(id, _)| id == &ids[0]).unwrap();
+            assert_eq!(*score, alice_score.saturating_sub(7));
+        }
+        _ => panic!("game should be finished"),
+    }
+}
+
+#[test]
+fn test_proxy_bids_auto_raise_up_to_their_maximum() {
+    let (mut game, ids) = setup_engine_game(&["Alice", "Bob", "Cara"], GameConfig::default());
+    game_engine::start_round(&mut game).unwrap();
+
+    // Alice's maximum opens at the minimum bid and stays hidden
+    game_engine::place_proxy_bid(&mut game, &ids[0], 40).unwrap();
+    assert_eq!(game.get_highest_bid().unwrap().amount, 1);
+    assert_eq!(game.get_highest_bid().unwrap().player_id, ids[0]);
+
+    // Bob's manual bid is answered straight away
+    game_engine::place_bid(&mut game, &ids[1], 20).unwrap();
+    let high = game.get_highest_bid().unwrap();
+    assert_eq!(
+        (high.player_id.as_str(), high.amount),
+        (ids[0].as_str(), 21)
+    );
+
+    // A bigger maximum wins by one increment over Alice's ceiling
+    game_engine::place_proxy_bid(&mut game, &ids[2], 60).unwrap();
+    let high = game.get_highest_bid().unwrap();
+    assert_eq!(
+        (high.player_id.as_str(), high.amount),
+        (ids[2].as_str(), 41)
+    );
+    assert!(game.get_proxy_bid(&ids[0]).is_none());
+
+    // Equal maximums go to whoever set theirs first
+    game_engine::place_proxy_bid(&mut game, &ids[1], 60).unwrap();
+    let high = game.get_highest_bid().unwrap();
+    assert_eq!(
+        (high.player_id.as_str(), high.amount),
+        (ids[2].as_str(), 60)
+    );
+
+    game_engine::resolve_round(&mut game).unwrap();
+    assert_eq!(game.players[&ids[2]].chips, 40);
+}
+
+#[test]
+fn test_trading_swaps_paintings_and_chips_between_rounds() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            trading_enabled: true,
+            ..GameConfig::default()
+        },
+    );
+    for bidder in [&ids[0], &ids[1]] {
+        game_engine::start_round(&mut game).unwrap();
+        game_engine::place_bid(&mut game, bidder, 10).unwrap();
+        game_engine::resolve_round(&mut game).unwrap();
+    }
+    let alice_art = game.players[&ids[0]].collection[0].id;
+    let bob_art = game.players[&ids[1]].collection[0].id;
+
+    // Trade forms pick paintings by the game's opaque handles, not art ids
+    let handle = game.art_handle(bob_art).unwrap();
+    assert_eq!(game.art_id_for_handle(handle), Some(bob_art));
+    let panel = partials::render_trade_panel_partial(&game, &ids[0], &game.id);
+    assert!(panel.contains(&format!(r#"name="requested_art" value="{}""#, handle)));
+    assert!(!panel.contains(&format!(r#"name="requested_art" value="{}""#, bob_art)));
+
+    // Alice can't offer what she doesn't own
+    assert!(
+        trading::propose_trade(&mut game, &ids[0], &ids[1], vec![bob_art], vec![], 0, 0).is_err()
+    );
+
+    // Chip amounts that would wrap negative are rejected
+    for (offered, requested) in [(u32::MAX, 0), (0, u32::MAX), (1 << 31, 0)] {
+        assert!(
+            trading::propose_trade(
+                &mut game,
+                &ids[0],
+                &ids[1],
+                vec![alice_art],
+                vec![],
+                offered,
+                requested,
+            )
+            .is_err()
+        );
+    }
+    assert!(game.trade_offers.is_empty());
+    let trade_id = trading::propose_trade(
+        &mut game,
+        &ids[0],
+        &ids[1],
+        vec![alice_art],
+        vec![bob_art],
+        5,
+        0,
+    )
+    .unwrap();
+
+    // Only the counterparty can accept
+    assert!(trading::accept_trade(&mut game, &ids[0], trade_id).is_err());
+    trading::accept_trade(&mut game, &ids[1], trade_id).unwrap();
+
+    assert_eq!(game.players[&ids[0]].collection[0].id, bob_art);
+    assert_eq!(game.players[&ids[1]].collection[0].id, alice_art);
+    assert_eq!(game.players[&ids[0]].chips, 85);
+    assert_eq!(game.players[&ids[1]].chips, 95);
+    assert!(game.trade_offers.is_empty());
+
+    // The window closes once the next round starts
+    trading::propose_trade(&mut game, &ids[0], &ids[1], vec![bob_art], vec![], 0, 0).unwrap();
+    game_engine::start_round(&mut game).unwrap();
+    assert!(game.trade_offers.is_empty());
+    assert!(
+        trading::propose_trade(&mut game, &ids[0], &ids[1], vec![bob_art], vec![], 0, 0).is_err()
+    );
+}
+
+#[test]
+fn test_sell_back_to_bank() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            sell_back_enabled: true,
+            sell_back_basis: SellBackBasis::PurchasePrice,
+            sell_back_percent: 50,
+            sell_back_destination: SellBackDestination::Deck,
+            ..GameConfig::default()
+        },
+    );
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::place_bid(&mut game, &ids[0], 30).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+    let art_id = game.players[&ids[0]].collection[0].id;
+    let deck_size = game.deck.len();
+
+    // Half the purchase price comes back and the piece returns to the deck
+    assert!(game_engine::sell_to_bank(&mut game, &ids[1], art_id).is_err());
+    assert_eq!(
+        game_engine::sell_to_bank(&mut game, &ids[0], art_id),
+        Ok(15)
+    );
+    assert_eq!(game.players[&ids[0]].chips, 85);
+    assert!(game.players[&ids[0]].collection.is_empty());
+    assert_eq!(game.deck.len(), deck_size + 1);
+    assert_eq!(game.deck[0].id, art_id);
+}
+
+#[test]
+fn test_market_values_grow_at_season_end() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            market_enabled: true,
+            season_length: 2,
+            num_rounds: 4,
+            ..GameConfig::default()
+        },
+    );
+
+    // Season one: two sales, so two artists (or one, twice) gain value
+    let mut sold = Vec::new();
+    for bidder in [&ids[0], &ids[1]] {
+        sold.push(game.current_art[0].artist);
+        game_engine::start_round(&mut game).unwrap();
+        game_engine::place_bid(&mut game, bidder, 10).unwrap();
+        game_engine::resolve_round(&mut game).unwrap();
+    }
+    assert_eq!(game.season, 2);
+    assert!(game.season_sales.is_empty());
+    assert_eq!(game.last_season_awards[0].0, sold[0]);
+    let top_value = if sold[0] == sold[1] { 5 } else { 5 + 3 };
+    assert_eq!(game.market_values.values().sum::<u32>(), top_value);
+
+    // Pieces score their artist's market value on top of the collection score
+    let alice = &game.players[&ids[0]];
+    assert_eq!(
+        game.final_score(alice),
+        alice.calculate_score(game.config.scoring) + game.market_values[&sold[0]]
+    );
+}
+
+#[test]
+fn test_secret_objectives_are_dealt_and_scored() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            secret_objectives: true,
+            num_rounds: 1,
+            ..GameConfig::default()
+        },
+    );
+
+    // Everyone gets a different objective
+    let alice_objective = game.players[&ids[0]].objective.unwrap();
+    let bob_objective = game.players[&ids[1]].objective.unwrap();
+    assert_ne!(alice_objective.id, bob_objective.id);
+
+    // Give Alice an objective she'll meet by keeping her chips
+    game.players.get_mut(&ids[0]).unwrap().objective = objectives::OBJECTIVES
+        .iter()
+        .find(|o| o.goal == ObjectiveGoal::ChipsAbove(50))
+        .copied();
+
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    match &game.state {
+        GameState::Finished { final_scores, .. } => {
+            let (_, score) = final_scores.iter().find(|(id, _)| id == &ids[0]).unwrap();
+            assert_eq!(*score, 10);
+        }
+        _ => panic!("game should be finished"),
+    }
+}
+
+#[test]
+fn test_last_round_loan_does_not_meet_chip_objective() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            secret_objectives: true,
+            loans_enabled: true,
+            num_rounds: 2,
+            ..GameConfig::default()
+        },
+    );
+    game.players.get_mut(&ids[0]).unwrap().objective = objectives::OBJECTIVES
+        .iter()
+        .find(|o| o.goal == ObjectiveGoal::ChipsAbove(50))
+        .copied();
+
+    // Alice spends down to 40 chips, then borrows 20 in the final round
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::place_bid(&mut game, &ids[0], 60).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::take_loan(&mut game, &ids[0], 20).unwrap();
+    assert_eq!(game.players[&ids[0]].chips, 60);
+    assert_eq!(game.objective_bonus(&game.players[&ids[0]]), 0);
+
+    game_engine::resolve_round(&mut game).unwrap();
+    let alice = &game.players[&ids[0]];
+    assert_eq!(alice.debt_penalty(), 0);
+    match &game.state {
+        GameState::Finished { final_scores, .. } => {
+            let (_, score) = final_scores.iter().find(|(id, _)| id == &ids[0]).unwrap();
+            assert_eq!(*score, game.final_score(alice));
+        }
+        _ => panic!("game should be finished"),
+    }
+}
+
+#[test]
+fn test_scoring_rules() {
+    // The README example: three Van Goghs (3, 2, 2 stars) and one Monet (3 stars)
+    let mut player = Player::new("p0".to_string(), "Alice".to_string(), 100);
+    player.collection = art_database::ART_PIECES[..4].to_vec();
+
+    assert_eq!(player.calculate_score(ScoringMode::MaxBonus), 24);
+    assert_eq!(player.calculate_score(ScoringMode::SumBonus), 48);
+    assert_eq!(player.calculate_score(ScoringMode::StarsOnly), 10);
+    // Ten stars plus one complete Van Gogh set
+    assert_eq!(player.calculate_score(ScoringMode::SetCompletion), 25);
+    // Ten stars plus two movements
+    assert_eq!(player.calculate_score(ScoringMode::Diversity), 18);
+}
+
+#[test]
+fn test_score_breakdown_itemizes_groups() {
+    let mut player = Player::new("p0".to_string(), "Alice".to_string(), 100);
+    player.collection = art_database::ART_PIECES[..4].to_vec();
+
+    let breakdown = player.score_breakdown(ScoringMode::MaxBonus);
+    assert_eq!(breakdown.artists.len(), 2);
+    let van_gogh = &breakdown.artists[0];
+    assert_eq!(
+        (
+            van_gogh.name,
+            van_gogh.stars,
+            van_gogh.count,
+            van_gogh.subtotal
+        ),
+        ("Vincent van Gogh", 7, 3, 21)
+    );
+    assert_eq!(breakdown.artist_total, 24);
+    assert_eq!(breakdown.movement_total, 24);
+    assert_eq!(breakdown.chosen, ChosenBonus::Artist);
+    assert_eq!(
+        breakdown.total,
+        player.calculate_score(ScoringMode::MaxBonus)
+    );
+
+    let breakdown = player.score_breakdown(ScoringMode::SumBonus);
+    assert_eq!(breakdown.chosen, ChosenBonus::Both);
+    assert_eq!(breakdown.total, 48);
+}
+
+#[test]
+fn test_tie_breaker_picks_a_single_winner() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            num_rounds: 1,
+            tie_breaker: TieBreaker::MostChips,
+            ..GameConfig::default()
+        },
+    );
+
+    // Nobody bids, so both finish on 0 points with Bob holding more chips
+    game.players.get_mut(&ids[1]).unwrap().chips += 5;
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    match &game.state {
+        GameState::Finished {
+            winner_ids,
+            final_scores,
+            tie_break,
+            ..
+        } => {
+            assert_eq!(winner_ids, &vec![ids[1].clone()]);
+            assert_eq!(final_scores[0].0, ids[1]);
+            assert_eq!(
+                tie_break.as_deref(),
+                Some("Alice and Bob tied on 0 points; Bob wins on most leftover chips")
+            );
+        }
+        _ => panic!("game should be finished"),
+    }
+}
+
+#[test]
+fn test_leftover_chips_score_points() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            num_rounds: 1,
+            chips_per_point: 10,
+            ..GameConfig::default()
+        },
+    );
+
+    // Debt is paid off before leftover chips are counted
+    game.players.get_mut(&ids[0]).unwrap().debt = 25;
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    match &game.state {
+        GameState::Finished {
+            winner_ids,
+            final_scores,
+            tie_break,
+            ..
+        } => {
+            assert_eq!(winner_ids, &vec![ids[1].clone()]);
+            assert_eq!(
+                final_scores,
+                &vec![(ids[1].clone(), 10), (ids[0].clone(), 7)]
+            );
+            assert!(tie_break.is_none());
+        }
+        _ => panic!("game should be finished"),
+    }
+}
+
+#[test]
+fn test_teams_share_a_score_and_chips() {
+    let mut game = Game::new("team-game".to_string(), "p0".to_string());
+    game.config = GameConfig {
+        teams_enabled: true,
+        team_scoring: TeamScoring::MergeCollections,
+        num_rounds: 1,
+        ..GameConfig::default()
+    };
+    for (i, name) in ["Alice", "Bob", "Carol", "Dave"].iter().enumerate() {
+        let player = Player::new(format!("p{}", i), name.to_string(), 100);
+        game.add_player(player).unwrap();
+    }
+
+    // Everyone needs a team before the game can start
+    assert!(game_engine::start_game(&mut game).is_err());
+    for (i, team) in [1, 1, 2, 2].iter().enumerate() {
+        game.players.get_mut(&format!("p{}", i)).unwrap().team = Some(*team);
+    }
+    game_engine::start_game(&mut game).unwrap();
+
+    // Chips only move between teammates
+    game_engine::transfer_chips(&mut game, "p0", "p1", 30).unwrap();
+    assert_eq!(game.players["p0"].chips, 70);
+    assert_eq!(game.players["p1"].chips, 130);
+    assert!(game_engine::transfer_chips(&mut game, "p0", "p2", 10).is_err());
+    assert!(game_engine::transfer_chips(&mut game, "p0", "p1", 500).is_err());
+    // Amounts that would wrap negative are rejected, not sent in reverse
+    assert!(game_engine::transfer_chips(&mut game, "p0", "p1", u32::MAX).is_err());
+    assert!(game_engine::transfer_chips(&mut game, "p0", "p1", 1 << 31).is_err());
+
+    // Two Van Goghs split across teammates score as one collection: (3 + 2) × 2
+    game.players.get_mut("p0").unwrap().collection = vec![art_database::ART_PIECES[0].clone()];
+    game.players.get_mut("p1").unwrap().collection = vec![art_database::ART_PIECES[1].clone()];
+    assert_eq!(game.team_score(1), 10);
+    game.config.team_scoring = TeamScoring::SumScores;
+    assert_eq!(game.team_score(1), 5);
+
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    match &game.state {
+        GameState::Finished { winner_ids, .. } => {
+            let mut winner_ids = winner_ids.clone();
+            winner_ids.sort();
+            assert_eq!(winner_ids, vec!["p0".to_string(), "p1".to_string()]);
+        }
+        _ => panic!("game should be finished"),
+    }
+}
+
+#[test]
+fn test_deck_strategies() {
+    let config = GameConfig {
+        num_rounds: 10,
+        ..GameConfig::default()
+    };
+    let mut rng = StdRng::seed_from_u64(7);
+
+    // Complete sets: whole artists only, rounded down to fit the rounds
+    let deck = art_database::build_deck(
+        &GameConfig {
+            deck_strategy: DeckStrategy::CompleteSets,
+            ..config.clone()
+        },
+        &mut rng,
+    );
+    assert_eq!(deck.len(), 9);
+    for art in &deck {
+        assert_eq!(deck.iter().filter(|a| a.artist == art.artist).count(), 3);
+    }
+
+    // Never more paintings than the rounds need, even when no whole set fits
+    for (num_rounds, lot_size, expected) in [(1, 1, 1), (2, 1, 2), (5, 4, 18), (4, 2, 6)] {
+        let deck = art_database::build_deck(
+            &GameConfig {
+                deck_strategy: DeckStrategy::CompleteSets,
+                num_rounds,
+                lot_size,
+                ..config.clone()
+            },
+            &mut rng,
+        );
+        assert_eq!(deck.len(), expected);
+    }
+
+    // Guaranteed: every Van Gogh is in, and the deck is still full size
+    let deck = art_database::build_deck(
+        &GameConfig {
+            deck_strategy: DeckStrategy::Guaranteed,
+            focus_artists: vec![Artist::VanGogh],
+            pieces_per_focus: 3,
+            ..config.clone()
+        },
+        &mut rng,
+    );
+    assert_eq!(deck.len(), 10);
+    assert_eq!(
+        deck.iter().filter(|a| a.artist == Artist::VanGogh).count(),
+        3
+    );
+
+    // Star mix: only three-star paintings, from the chosen movements
+    let deck = art_database::build_deck(
+        &GameConfig {
+            deck_strategy: DeckStrategy::StarTarget,
+            star_weights: [0, 0, 1],
+            deck_movements: vec![Movement::Impressionism, Movement::Baroque],
+            num_rounds: 4,
+            ..config.clone()
+        },
+        &mut rng,
+    );
+    assert_eq!(deck.len(), 4);
+    assert!(deck.iter().all(|a| a.stars == 3));
+    assert!(
+        deck.iter()
+            .all(|a| matches!(a.movement, Movement::Impressionism | Movement::Baroque))
+    );
+}
+
+#[test]
+fn test_seeded_games_deal_the_same_deck() {
+    let config = GameConfig {
+        seed: Some(42),
+        secret_objectives: true,
+        ..GameConfig::default()
+    };
+    let (first, ids) = setup_engine_game(&["Alice", "Bob", "Carol"], config.clone());
+    let (second, _) = setup_engine_game(&["Alice", "Bob", "Carol"], config);
+
+    let deck_ids = |game: &Game| -> Vec<usize> { game.deck.iter().map(|a| a.id).collect() };
+    assert_eq!(deck_ids(&first), deck_ids(&second));
+    assert_eq!(first.current_art[0].id, second.current_art[0].id);
+    assert_eq!(first.turn_order, second.turn_order);
+    for id in &ids {
+        assert_eq!(
+            first.players[id].objective.unwrap().id,
+            second.players[id].objective.unwrap().id
+        );
+    }
+
+    // Unseeded games record the seed they used, so they can be replayed too
+    let (random, _) = setup_engine_game(&["Alice", "Bob"], GameConfig::default());
+    let (replay, _) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            seed: random.seed,
+            ..GameConfig::default()
+        },
+    );
+    assert_eq!(deck_ids(&random), deck_ids(&replay));
+    assert_eq!(random.current_art[0].id, replay.current_art[0].id);
+
+    // Seating and objectives follow join order, not the generated player ids
+    let seated = |ids: [&str; 3]| {
+        let mut game = Game::new("seeded".to_string(), ids[0].to_string());
+        game.config = GameConfig {
+            seed: Some(42),
+            secret_objectives: true,
+            ..GameConfig::default()
+        };
+        for (id, name) in ids.iter().zip(["Alice", "Bob", "Carol"]) {
+            game.add_player(Player::new(id.to_string(), name.to_string(), 100))
+                .unwrap();
+        }
+        game_engine::start_game(&mut game).unwrap();
+        game.turn_order
+            .iter()
+            .map(|id| {
+                let player = &game.players[id];
+                (player.name.clone(), player.objective.unwrap().id)
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(seated(["zed", "amy", "kim"]), seated(["a1", "b2", "c3"]));
+}
+
+#[test]
+fn test_upcoming_preview_matches_next_lot() {
+    let (mut game, _) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            lot_size: 2,
+            preview_count: 3,
+            ..GameConfig::default()
+        },
+    );
+
+    let upcoming: Vec<(usize, usize)> = game
+        .upcoming_art()
+        .iter()
+        .map(|(lot, art)| (*lot, art.id))
+        .collect();
+    assert_eq!(upcoming.len(), 3);
+    assert_eq!(
+        upcoming.iter().map(|(lot, _)| *lot).collect::<Vec<_>>(),
+        vec![1, 1, 2]
+    );
+
+    // Nobody bids, so the previewed lot comes up next
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+    let mut dealt: Vec<usize> = game.current_art.iter().map(|a| a.id).collect();
+    let mut previewed: Vec<usize> = upcoming[..2].iter().map(|(_, id)| *id).collect();
+    dealt.sort();
+    previewed.sort();
+    assert_eq!(dealt, previewed);
+    assert!(
+        !game
+            .upcoming_art()
+            .iter()
+            .any(|(_, a)| dealt.contains(&a.id))
+    );
+}
+
+#[test]
+fn test_blind_lots_hide_attributes_until_revealed() {
+    let config = GameConfig {
+        blind_lot_percent: 100,
+        blind_hides: BlindHides::Everything,
+        blind_reveal: BlindReveal::Winner,
+        seed: Some(7),
+        ..GameConfig::default()
+    };
+    let (mut game, _) = setup_engine_game(&["Alice", "Bob"], config.clone());
+
+    let art = game.current_art[0].clone();
+    let masked = game.masked_art(&art, None);
+    assert!(masked.is_blind);
+    assert_eq!(masked.name, "Untitled");
+    assert_eq!(masked.artist, None);
+    assert_eq!(masked.movement, None);
+    assert_eq!(masked.stars, None);
+    assert!(!partials::render_current_art_partial(&game).contains(art.name));
+
+    // Only the winner learns what they bought
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::place_bid(&mut game, "p0", 10).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+    assert!(!game.masked_art(&art, Some("p0")).is_blind);
+    assert_eq!(game.masked_art(&art, Some("p0")).stars, Some(art.stars));
+    assert!(game.masked_art(&art, Some("p1")).is_blind);
+
+    // Revealed to everyone once the round resolves
+    let (mut game, _) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            blind_reveal: BlindReveal::Everyone,
+            ..config
+        },
+    );
+    let art = game.current_art[0].clone();
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+    assert!(!game.hidden_art.contains_key(&art.id));
+    assert!(!game.masked_art(&art, None).is_blind);
+}
+
+#[test]
+fn test_market_board_keeps_blind_artists_hidden() {
+    let (mut game, ids) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            market_enabled: true,
+            season_length: 2,
+            num_rounds: 2,
+            blind_lot_percent: 100,
+            blind_hides: BlindHides::Everything,
+            blind_reveal: BlindReveal::Winner,
+            ..GameConfig::default()
+        },
+    );
+
+    let artist = game.current_art[0].artist;
+    game_engine::start_round(&mut game).unwrap();
+    game_engine::place_bid(&mut game, &ids[0], 10).unwrap();
+    game_engine::resolve_round(&mut game).unwrap();
+
+    // The sale still counts for its artist, but the board only shows an unrevealed piece
+    assert_eq!(game.season_sales, vec![(artist, 1)]);
+    let board = partials::render_market_board_partial(&game);
+    assert!(!board.contains(artist.name()));
+    assert!(board.contains("Unrevealed"));
+}
+
+#[test]
+fn test_forgeries_exposed_at_game_end() {
+    let (mut game, _) = setup_engine_game(
+        &["Alice", "Bob"],
+        GameConfig {
+            num_rounds: 4,
+            forgery_percent: 50,
+            forgery_penalty: 3,
+            authentication_enabled: true,
+            authentication_cost: 10,
+            seed: Some(11),
+            ..GameConfig::default()
+        },
+    );
+    assert_eq!(game.forgeries.len(), 2);
+
+    // Hand Alice one fake and one genuine painting
+    let fake = game.deck.pop().unwrap();
+    let genuine = game.deck.pop().unwrap();
+    game.forgeries = [fake.id].into_iter().collect();
+    let alice = game.players.get_mut("p0").unwrap();
+    alice.collection.push(fake.clone());
+    alice.collection.push(genuine.clone());
+
+    // Authentication is paid for and can't be repeated
+    assert_eq!(
+        game_engine::authenticate_art(&mut game, "p0", fake.id),
+        Ok(false)
+    );
+    assert_eq!(
+        game_engine::authenticate_art(&mut game, "p0", genuine.id),
+        Ok(true)
+    );
+    assert!(game_engine::authenticate_art(&mut game, "p0", fake.id).is_err());
+    assert!(game_engine::authenticate_art(&mut game, "p1", fake.id).is_err());
+    assert_eq!(game.players["p0"].chips, 80);
+
+    // Nobody bids until the deck runs out
+    while !matches!(game.state, GameState::Finished { .. }) {
+        game_engine::start_round(&mut game).unwrap();
+        game_engine::resolve_round(&mut game).unwrap();
+    }
+
+    let alice = &game.players["p0"];
+    assert_eq!(alice.forgeries.len(), 1);
+    assert_eq!(alice.forgeries[0].id, fake.id);
+    assert!(!alice.owns_art(fake.id));
+    assert!(alice.owns_art(genuine.id));
+    assert_eq!(game.forgery_penalty(alice), 3);
+    assert_eq!(
+        game.final_score(alice),
+        (alice.calculate_score(ScoringMode::MaxBonus) + game.chip_points(alice)).saturating_sub(3)
+    );
 }